@@ -5,6 +5,7 @@ physical = Physical
 social = Social
 
 unskilled = (-{ $num } unskilled)
+over-cap = Above their cap: { $traits }
 
 attributes = Attributes
 attribute = Attribute
@@ -29,31 +30,55 @@ skills = Skills
 skill = Skill
 
 academics = Academics
+    .specialties = Anthropology, Art History, English, History, Law, Religion, Research
 computer = Computer
+    .specialties = Artificial Intelligence, Data Retrieval, Graphics, Hacking, Programming
 crafts = Crafts
+    .specialties = Automotive, Cooking, Forgery, Jury-Rigging, Sculpting
 investigation = Investigation
+    .specialties = Artifacts, Autopsies, Body Language, Crime Scenes, Cryptography, Dreams, Lab Work, Riddles
 medicine = Medicine
+    .specialties = First Aid, Pathology, Pharmaceuticals, Physical Therapy, Surgery
 occult = Occult
+    .specialties = Casting, Cultural Beliefs, Ghosts, Magic, Monsters, Superstitions, Witchcraft
 politics = Politics
+    .specialties = Bureaucracy, Church, Local, Organized Crime, Scandals
 science = Science
+    .specialties = Astronomy, Biology, Chemistry, Engineering, Physics
 
 athletics = Athletics
+    .specialties = Acrobatics, Climbing, Kayaking, Long-Distance Running, Sprinting, Swimming, Throwing
 brawl = Brawl
+    .specialties = Boxing, Claws, Dirty Tricks, Grappling, Martial Arts, Threats
 drive = Drive
+    .specialties = Defensive Driving, Motorcycles, Off-Road Driving, Pursuit, Stunts
 firearms = Firearms
+    .specialties = Handguns, Rifles, Shotguns, Trick Shots
 larceny = Larceny
+    .specialties = Breaking and Entering, Lockpicking, Pickpocketing, Safecracking, Security Systems, Sleight of Hand
 stealth = Stealth
+    .specialties = Camouflage, Crowds, In Plain Sight, Rural, Shadowing, Stalking, Stakeouts
 survival = Survival
+    .specialties = Foraging, Hunting, Meteorology, Navigation, Shelter
 weaponry = Weaponry
+    .specialties = Chains, Improvised Weapons, Knives, Sabres, Spears, Stakes
     
 animal-ken = Animal Ken
+    .specialties = Canines, Felines, Reptiles, Soothing, Training
 empathy = Empathy
+    .specialties = Calming, Emotion, Lies, Motives, Personalities
 expression = Expression
+    .specialties = Dance, Drama, Journalism, Musical Composition, Musical Instrument, Poetry, Writing
 intimidation = Intimidation
+    .specialties = Bluster, Direct Threats, Physical Threats, Stare Down, Torture, Veiled Threats
 persuasion = Persuasion
+    .specialties = Fast-Talking, Inspiring, Sales Pitch, Seduction, Sermons
 socialize = Socialize
+    .specialties = Bar Hopping, Formal Events, Frat Parties, Political Fundraisers, The Club
 streetwise = Streetwise
+    .specialties = Black Market, Gangs, Rumours, Undercover
 subterfuge = Subterfuge
+    .specialties = Detecting Lies, Hiding Emotion, Little White Lies, Misdirection
 
 name = Name
 player = Player
@@ -377,106 +402,312 @@ sex = Sex
 
 merits = Merit
 area-of-expertise = Area of Expertise
+    .description = Choose one of your Specialties; it adds two dice instead of one.
+    .source = Chronicles of Darkness
 common-sense = Common Sense
+    .description = Once per chapter, ask the Storyteller what the wisest course of action would be, and get a straight answer.
+    .source = Chronicles of Darkness
 danger-sense = Danger Sense
+    .description = Gain +2 to rolls to detect an impending ambush.
+    .source = Chronicles of Darkness
 direction-sense = Direction Sense
+    .description = You always know your way back and never get lost; take no penalties to navigate.
+    .source = Chronicles of Darkness
 eidetic-memory = Eidetic Memory
+    .description = You recall anything you've seen or heard with perfect accuracy; gain +2 to memory rolls.
+    .source = Chronicles of Darkness
 encyclopedic-knowledge = Encyclopedic Knowledge
+    .description = Pick a Knowledge area; you can make an Intelligence + Wits roll to recall a pertinent fact about it.
+    .source = Chronicles of Darkness
 eye-for-the-strange = Eye for the Strange
+    .description = You can tell when something has a supernatural explanation, though not what it is.
+    .source = Chronicles of Darkness
 fast-reflexes = Fast Reflexes
+    .description = Each dot adds one to Initiative.
+    .dot-1 = +1 Initiative.
+    .dot-2 = +2 Initiative.
+    .dot-3 = +3 Initiative.
+    .source = Chronicles of Darkness
 good-time-management = Good Time Management
+    .description = Halve the time needed for extended actions.
+    .source = Chronicles of Darkness
 holistic-awareness = Holistic Awareness
+    .description = You can treat wounds and illnesses with what's at hand, ignoring the lack of proper equipment.
+    .source = Chronicles of Darkness
 human-prey = Human Prey
 hypervigilance = Hypervigilance
 indomitable = Indomitable
+    .description = Add two dice to resist supernatural attempts to influence your mind.
+    .source = Chronicles of Darkness
 interdisciplinary-specialty = Interdisciplinary Specialty
+    .description = Apply one Specialty to rolls with any Skill where it makes sense.
+    .source = Chronicles of Darkness
 investigative-aide = Investigative Aide
+    .description = Pick a Skill; when investigating with it, one success counts as an exceptional success.
+    .source = Chronicles of Darkness
 investigative-prodigy = Investigative Prodigy
+    .description = When investigating a scene, uncover extra Clues, up to your Merit dots or Wits.
+    .source = Chronicles of Darkness
 language = Language
+    .description = You're fluent in one more language, chosen when you take the Merit.
+    .source = Chronicles of Darkness
 library = Library
+    .description = A collection of research materials on one topic; add its dots to extended research rolls.
+    .source = Chronicles of Darkness
 library-advanced = Library, Advanced
+    .description = Your Library holds rare or occult material that grants benefits beyond simple research.
+    .source = Chronicles of Darkness
 lucid-dreamer = Lucid Dreamer
 meditative-mind = Meditative Mind
+    .description = Meditation comes easily to you and grants more than focus.
+    .dot-1 = No penalties from the environment while meditating.
+    .dot-2 = Meditate to gain the Calm Condition.
+    .dot-4 = Meditation restores a point of Willpower once per day.
+    .source = Chronicles of Darkness
 multilingual = Multilingual
+    .description = You can get by in two more languages, though not fluently.
+    .source = Chronicles of Darkness
 object-fetishism = Object Fetishism
 patient = Patient
+    .description = Take two additional rolls on extended actions.
+    .source = Chronicles of Darkness
 renowned-artisan = Renowned Artisam
 scarred = Scarred
 tolerance-for-biology = Tolerance for Biology
+    .description = Blood, gore and the grotesque don't faze you; no penalties for resisting disgust.
+    .source = Chronicles of Darkness
 trained-observer = Trained Observer,
+    .description = Your eye for detail grants extra dice on Perception rolls.
+    .dot-1 = Take a 9-again on Perception rolls.
+    .dot-3 = Take an 8-again on Perception rolls.
+    .source = Chronicles of Darkness
 vice-ridden = Vice-Ridden
+    .description = You have two Vices, and can regain Willpower from either of them.
+    .source = Chronicles of Darkness
 virtuous = Virtuous
+    .description = You have two Virtues, and can regain Willpower from either of them.
+    .source = Chronicles of Darkness
     
 ambidextrous = Ambidextrous
+    .description = You suffer no penalty for using your off hand.
+    .source = Chronicles of Darkness
 automotive-genius = Automotive Genius
+    .description = Your Crafts Specialty in vehicles lets you modify cars well beyond their factory specs.
+    .source = Chronicles of Darkness
 covert-operative = Covert Operative
+    .description = You're skilled at working unnoticed: gain bonuses to stealthy approaches and setting up ambushes.
+    .source = Chronicles of Darkness
 crack-driver = Crack Driver
+    .description = You drive with calm precision, even in the most dangerous circumstances.
+    .dot-2 = Add Composure to Drive rolls in dangerous situations.
+    .dot-3 = Once per turn, take a reflexive Drive action.
+    .source = Chronicles of Darkness
 demolisher = Demolisher
+    .description = Ignore one point of an object's Durability per dot when breaking it.
+    .source = Chronicles of Darkness
 double-jointed = Double Jointed
+    .description = You automatically escape from mundane bonds, and gain bonuses to escape grapples.
+    .source = Chronicles of Darkness
 fleet-of-foot = Fleet of Foot
+    .description = Each dot adds one to Speed, and pursuers take a penalty to catch you.
+    .dot-1 = +1 Speed.
+    .dot-2 = +2 Speed.
+    .dot-3 = +3 Speed.
+    .source = Chronicles of Darkness
 freediving = Freediving
+    .description = You can hold your breath for much longer than normal.
+    .source = Chronicles of Darkness
 giant = Giant
+    .description = You are massive: Size 6, with one extra box of Health.
+    .source = Chronicles of Darkness
 hardy = Hardy
+    .description = Add your dots to rolls against disease, poison, deprivation and suffocation.
+    .source = Chronicles of Darkness
 greyhound = Greyhound
+    .description = When chasing or being chased, you get an exceptional success on three successes instead of five.
+    .source = Chronicles of Darkness
 iron-skin = Iron Skin
+    .description = Your body shrugs off blows, giving you armor against bashing damage.
+    .dot-2 = 1 point of armor against bashing damage.
+    .dot-4 = 2 points of armor against bashing damage.
+    .source = Chronicles of Darkness
 iron-stamina = Iron Stamina
+    .description = Each dot ignores one point of negative modifiers from fatigue or injury.
+    .source = Chronicles of Darkness
 quick-draw = Quick Draw
+    .description = Pick a weapon type; drawing or holstering it is reflexive.
+    .source = Chronicles of Darkness
 punch-drunk = Punch Drunk
+    .description = You can keep fighting through a beating, ignoring the wound penalty from bashing damage.
+    .source = Chronicles of Darkness
 relentless = Relentless
+    .description = In a foot chase, opponents need two more successes to get away from you.
+    .source = Chronicles of Darkness
 roadkill = Roadkill
 seizing-the-edge = Seizing the Edge
+    .description = You always have the Edge in the first turn of a chase.
+    .source = Chronicles of Darkness
 sleight-of-hand = Sleight of Hand
+    .description = Take one Larceny action reflexively each turn; your actions with Larceny go unnoticed unless someone is looking.
+    .source = Chronicles of Darkness
 small-framed = Small Framed
+    .description = You are tiny: Size 4, with one less Health box, but +2 to hide and go unnoticed.
+    .source = Chronicles of Darkness
 survivalist = Survivalist
+    .description = You can handle extreme environments, ignoring the Extreme Cold and Extreme Heat Tilts for a time.
+    .source = Chronicles of Darkness
     
 air-of-menace = Air of Menace
+    .description = Your presence intimidates; gain +2 to Intimidation and others hesitate to attack.
+    .source = Chronicles of Darkness
 allies = Allies
+    .description = Help from an organization or group, rated separately for each one.
+    .source = Chronicles of Darkness
 alternate-identity = Alternate Identity
+    .description = You maintain a second identity with its own paper trail.
+    .source = Chronicles of Darkness
 anonymity = Anonymity
+    .description = You're hard to track: each dot is a penalty to rolls to find you through records and paper trails.
+    .source = Chronicles of Darkness
 barfly = Barfly
+    .description = You can always find your way into a bar or club, even without an invitation.
+    .source = Chronicles of Darkness
 closed-book = Closed Book
+    .description = Add your dots to resist attempts to read, manipulate or persuade you.
+    .source = Chronicles of Darkness
 cohesive-unit = Cohesive Unit
+    .description = Your team works in unison: extra successes when you're the primary actor in teamwork.
+    .source = Chronicles of Darkness
 contacts = Contacts
+    .description = Each dot is a sphere of influence where you can gather information.
+    .source = Chronicles of Darkness
 defender = Defender
+    .description = When protecting someone you're loyal to, add your dots to Defense-related rolls.
+    .source = Chronicles of Darkness
 empath = Empath
+    .description = You can read someone's emotional state with an Empathy roll.
+    .source = Chronicles of Darkness
 fame = Fame
+    .description = You're recognized in public; each dot adds to Social rolls with those who know you, and penalizes hiding.
+    .source = Chronicles of Darkness
 fixer = Fixer
+    .description = You know who to ask; reduce the Availability of services by one.
+    .source = Chronicles of Darkness
 hobbyist-clique = Hobbyist Clique
+    .description = You belong to a group of enthusiasts who share a Skill, giving you +2 with it and access to their help.
+    .source = Chronicles of Darkness
 inspiring = Inspiring
+    .description = Once per scene, rally others with a Presence + Expression roll; they regain Willpower.
+    .source = Chronicles of Darkness
 iron-will = Iron Will
+    .description = When spending Willpower to resist, add your Resolve instead of three dice.
+    .source = Chronicles of Darkness
 mentor = Mentor
+    .description = An expert takes you under their wing; choose three Skills they're trained in.
+    .source = Chronicles of Darkness
 peacemaker = Peacemaker
+    .description = You can talk people down; gain bonuses to end a fight without violence.
+    .source = Chronicles of Darkness
 pusher = Pusher
+    .description = When someone takes a bribe or gift from you, gain +1 to your next roll to influence them.
+    .source = Chronicles of Darkness
 resources = Resources
+    .description = Your disposable income. Things of Availability up to your dots are within your means.
+    .dot-1 = Scraping by.
+    .dot-2 = Comfortable.
+    .dot-3 = Well off.
+    .dot-4 = Rich.
+    .dot-5 = Extremely wealthy.
+    .source = Chronicles of Darkness
 retainer = Retainer
+    .description = An assistant, servant or follower who does as you ask.
+    .source = Chronicles of Darkness
 safe-place = Safe Place
+    .description = A secure location; each dot adds to Initiative there and penalizes intruders.
+    .source = Chronicles of Darkness
 small-unit-tactics = Small Unit Tactics
+    .description = Once per scene, direct allies with a Presence + Expression roll to act in concert.
+    .source = Chronicles of Darkness
 spin-doctor = Spin Doctor
+    .description = Manage damaging rumors: reduce penalties from your Notoriety and similar Conditions.
+    .source = Chronicles of Darkness
 staff = Staff
+    .description = A group of employees who handle routine tasks in one area of expertise.
+    .source = Chronicles of Darkness
 status = Status
+    .description = Standing within a group or organization; add your dots to Social rolls with its members.
+    .source = Chronicles of Darkness
 striking-looks = Striking Looks
+    .description = You're remarkably attractive; gain a bonus to Social rolls where looks matter.
+    .dot-1 = +1 to Social rolls where looks matter.
+    .dot-2 = +2 to Social rolls where looks matter.
+    .source = Chronicles of Darkness
 support-network = Support Network
+    .description = Friends and family who look after you and help you recover Willpower.
+    .source = Chronicles of Darkness
 sympathetic = Sympathetic
+    .description = You can take a Condition to gain the sympathy of your listener and open Doors.
+    .source = Chronicles of Darkness
 table-turner = Table Turner
+    .description = When someone tries to influence you, you may act before them to gain the upper hand.
+    .source = Chronicles of Darkness
 takes-one-to-know-one = Takes One to Know One
+    .description = You gain +2 to notice someone who shares your Vice, and they have trouble deceiving you.
+    .source = Chronicles of Darkness
 taste = Taste
+    .description = You have refined taste; spend a point of Willpower to notice the flaws and value of crafted works.
+    .source = Chronicles of Darkness
 true-friend = True Friend
+    .description = A friend who is loyal to the end; their help can't be bought or supernaturally turned against you.
+    .source = Chronicles of Darkness
 untouchable = Untouchable
+    .description = You cover your tracks; the law can't link you to your crimes unless they find direct evidence.
+    .source = Chronicles of Darkness
     
 professional-training = Professional Training
+    .description = You've been trained in a profession, chosen with two Asset Skills.
+    .dot-1 = Networking: one extra dot of Contacts within the profession.
+    .dot-2 = Breadth of Knowledge: two free Specialties in the Asset Skills.
+    .dot-3 = Continuing Education: 9-again on Asset Skill rolls.
+    .dot-4 = On the Job Training: a free Skill dot.
+    .dot-5 = The Routine: spend Willpower for the rote quality on an Asset Skill.
+    .source = Chronicles of Darkness
 aggressive-driving = Aggressive Driving
+    .description = You drive to bring other cars down; add your Drive to ramming damage.
+    .source = Chronicles of Darkness
 drone-control = Drone Control
 falconry = Falconry
 k9 = K9
 parkour = Parkour
+    .description = A Fighting Style for free running.
+    .dot-1 = Flow: ignore penalties from difficult terrain when running.
+    .dot-2 = Cat Leap: reduce falling damage.
+    .dot-3 = Wall Run: climb walls as part of a move.
+    .dot-4 = Expert Traceur: run across obstacles without a roll.
+    .dot-5 = Freeflow: take an extra action in a chase.
+    .source = Chronicles of Darkness
 stunt-driver = Stunt Driver
+    .description = A Fighting Style for high-speed and stunt driving.
+    .dot-1 = Defensive Driving: reduce ramming damage.
+    .dot-2 = Speed Demon: drive at top speed without penalties.
+    .dot-3 = Drive-by: drive and take another action in the same turn.
+    .source = Chronicles of Darkness
     
 etiquette = Etiquette
 fast-talking = Fast Talking
+    .description = A Social Style for overwhelming the target with words.
+    .dot-1 = Always Be Closing: a bonus for dealing with people you have just met.
+    .dot-2 = Jargon: baffle the target with technical terms.
+    .dot-3 = Devil's Advocate: get a second try after a failure.
+    .dot-4 = Salting the Mine: prepare an opening before the pitch.
+    .dot-5 = The Nigerian Scam: con the target into confirming the lie.
+    .source = Chronicles of Darkness
 mystery-cult-initation = Mystery Cult Initation
 scorpion-cult-initation = Scorpion Cult Initation
     
 defensive-combat = Defensive Combat
+    .description = Use Brawl or Weaponry to calculate Defense instead of Athletics.
+    .source = Chronicles of Darkness
     
 relentless-assault = Relentless Assault
     
@@ -485,4 +716,371 @@ high-speech = High Speech
     
     
 efficient-killer = Efficient Killer
-totem = Totem
\ No newline at end of file
+totem = Totem
+dice = Dice
+pool = Pool
+roll = Roll
+reset = Reset
+clear = Clear
+manual = Manual
+unnamed = Unnamed
+modifier = Modifier
+opponent = Opponent
+unskilled-label = unskilled
+wound-penalty = Wound penalty
+supernatural-power = Supernatural power
+resistance-of = { $name }'s Resistance
+
+roll-simple = Simple roll
+roll-resisted = Resisted roll
+roll-contested = Contested roll
+
+ten-again = 10-again
+nine-again = 9-again
+eight-again = 8-again
+no-again = No 10-again
+
+dramatic-failure = Dramatic Failure
+exceptional-success = Exceptional Success
+winner = { $name } wins
+tie = Tie
+
+damage = Damage
+bashing = bashing
+lethal = lethal
+aggravated = aggravated
+unknown-traits = Unknown: { $traits }
+none = None
+
+encounter = Encounter
+round = Round { $round }
+add-character = Add character
+add-npc = Add NPC
+roll-initiative = Roll initiative
+next-turn = Next turn
+delay = Delay
+act = Act now
+acting = Acting
+end-encounter = End encounter
+apply-damage = Apply damage
+remove = Remove
+unarmed = Unarmed
+
+take = Take
+heal = Heal
+heal-with = Heal with { $fuel }
+advance-time = Advance time
+next-heal = Next box heals in { $time } (all healed in { $total })
+duration = { $days }d { $hours }h { $minutes }m
+minutes = Minutes
+hours = Hours
+days = Days
+weeks = Weeks
+
+armor-rating = Rating
+coverage = Coverage
+equipped = Equipped
+armor-too-heavy = { $name } (too heavy)
+
+inventory = Inventory
+durability = Durability
+structure = Structure
+availability = Availability
+equipment-bonus = Equipment bonus
+state = State
+carried = Carried
+stashed = Stashed
+
+weapon = Weapon
+gear = Gear
+search-catalog = Search equipment…
+
+weapon-sap = Sap
+weapon-brass-knuckles = Brass Knuckles
+weapon-club = Club
+weapon-knife = Knife
+weapon-stake = Stake
+weapon-hatchet = Hatchet
+weapon-sword = Sword
+weapon-greatsword = Greatsword
+weapon-spear = Spear
+weapon-fire-axe = Fire Axe
+weapon-chainsaw = Chainsaw
+weapon-light-revolver = Revolver, light
+weapon-heavy-revolver = Revolver, heavy
+weapon-light-pistol = Pistol, light
+weapon-heavy-pistol = Pistol, heavy
+weapon-small-smg = SMG, small
+weapon-large-smg = SMG, large
+weapon-rifle = Rifle
+weapon-assault-rifle = Assault Rifle
+weapon-shotgun = Shotgun
+weapon-crossbow = Crossbow
+
+armor-reinforced-clothing = Reinforced Clothing
+armor-sports-gear = Sports Gear
+armor-kevlar-vest = Kevlar Vest
+armor-flak-jacket = Flak Jacket
+armor-riot-gear = Full Riot Gear
+armor-leather = Leather Armor
+armor-chainmail = Chainmail
+armor-plate = Plate Armor
+
+gear-lockpicks = Lockpicking Kit
+gear-first-aid-kit = First Aid Kit
+gear-crime-scene-kit = Crime Scene Kit
+gear-climbing-gear = Climbing Gear
+gear-toolkit = Toolkit
+gear-mechanics-toolkit = Mechanic's Toolkit
+gear-laptop = Laptop
+gear-survival-kit = Survival Kit
+gear-disguise-kit = Disguise Kit
+gear-flashlight = Flashlight
+gear-binoculars = Binoculars
+
+buy = Buy
+cancel = Cancel
+recover = Recover
+resources-left = Resources { $rating } (available { $effective })
+purchase-free = Within your means.
+purchase-above = Beyond your means: it takes a Wits + Streetwise roll, or Resources drop by one until the end of the chapter.
+purchase-failed = The roll failed: { $roll }
+reduce-resources = Reduce Resources
+
+general-armor = General armor
+ballistic-armor = Ballistic armor
+combat-rolls = Combat rolls
+mental-rolls = Mental rolls
+physical-rolls = Physical rolls
+social-rolls = Social rolls
+all-rolls = All rolls
+
+resolve-condition = Resolve
+persistent = Persistent
+take-beat = Beat
+add-condition = Add condition
+custom-condition = Custom condition
+
+condition-addicted = Addicted
+    .description = Your character is addicted to something. Each time they go without it longer than they can manage, they risk losing Willpower or other penalties.
+condition-amnesia = Amnesia
+    .description = Your character is missing a part of their memory. Resolve when they recover the lost memories.
+condition-blind = Blind
+    .description = Your character cannot see. Take -3 to rolls relying on sight and halve Defense in combat.
+condition-broken = Broken
+    .description = Something has broken your character's will. Take -2 to all Social rolls; they cannot spend Willpower on Resolve or Composure rolls.
+condition-embarrassing-secret = Embarrassing Secret
+    .description = Your character has a secret that would be mortifying if it came out. Resolve when the secret is revealed.
+condition-fugue = Fugue
+    .description = Something terrible happened, and your character blocked it out. Resolve when they face what they lost.
+condition-guilty = Guilty
+    .description = Your character is wracked with guilt. Take -2 to resist Persuasion and Intimidation. Resolve by confessing.
+condition-informed = Informed
+    .description = Your character's research paid off. Use the information to gain an exceptional success on a relevant roll.
+condition-inspired = Inspired
+    .description = Your character is struck by inspiration. Spend it to gain an exceptional success on three successes instead of five.
+condition-leveraged = Leveraged
+    .description = Someone has something on your character. Resolve by doing what they demand or by breaking free of their hold.
+condition-madness = Madness
+    .description = Your character's mind is fraying. Resolve when they face a breaking point that pushes them further.
+condition-notoriety = Notoriety
+    .description = Your character is known for something unpleasant. Take -2 on Social rolls against people who know of it.
+condition-obsession = Obsession
+    .description = Something fascinates your character. Gain 9-again on rolls pursuing it, and -2 on rolls that distract from it.
+condition-shaken = Shaken
+    .description = Something has frightened your character badly. Resolve when they give in to their fear.
+condition-spooked = Spooked
+    .description = Your character has seen something supernatural. Resolve when they seek out or flee from the source.
+condition-steadfast = Steadfast
+    .description = Your character is confident in their course of action. Treat a chance die as a normal die once.
+condition-swooned = Swooned
+    .description = Your character is attracted to someone. Take -2 to rolls against them. Resolve by doing what they ask.
+condition-distracted = Distracted
+    .description = Your character's mind is elsewhere. Take -2 to Mental rolls.
+
+effect-halved = { $target } halved
+effect-lost = No { $target }
+
+tilts = Tilts
+add-tilt = Add Tilt
+turns = Turns
+tilt-turns = { $name } ({ $turns ->
+    [one] 1 turn
+   *[other] { $turns } turns
+})
+
+tilt-arm-wrack = Arm Wrack
+    .description = One of the character's arms is injured. They drop whatever it was holding and can't use it.
+tilt-beaten-down = Beaten Down
+    .description = The character has had the fight knocked out of them. Taking violent action costs a point of Willpower.
+tilt-blinded = Blinded
+    .description = The character can't see properly. Attacks suffer and Defense is halved.
+tilt-deafened = Deafened
+    .description = The character can't hear. Perception rolls relying on hearing and combat actions suffer.
+tilt-drugged = Drugged
+    .description = The character is under the influence. Every roll suffers a penalty.
+tilt-immobilized = Immobilized
+    .description = The character is held or tied down. They can't move or apply Defense until they break free.
+tilt-insane = Insane
+    .description = The character's mind has given way in the heat of the moment. Their Defense suffers.
+tilt-knocked-down = Knocked Down
+    .description = The character is knocked off their feet. If they haven't acted yet this turn, they lose their action.
+tilt-leg-wrack = Leg Wrack
+    .description = One of the character's legs is injured. Speed is halved and Physical rolls suffer.
+tilt-poisoned = Poisoned
+    .description = The character is poisoned and takes damage every turn until treated.
+tilt-sick = Sick
+    .description = The character is ill. Physical rolls suffer.
+tilt-stunned = Stunned
+    .description = The character is dazed. They lose their next action and Defense is halved until then.
+tilt-blizzard = Blizzard
+    .description = Heavy snow slows movement and makes physical actions harder.
+tilt-earthquake = Earthquake
+    .description = The ground shakes. Physical rolls suffer and the tremors may cause damage.
+tilt-extreme-cold = Extreme Cold
+    .description = The cold saps the character's strength. Every roll suffers.
+tilt-extreme-heat = Extreme Heat
+    .description = The heat saps the character's strength. Every roll suffers.
+tilt-flooded = Flooded
+    .description = Rising water slows movement and makes physical actions harder.
+tilt-heavy-rain = Heavy Rain
+    .description = Rain obscures vision and sound, making attacks harder.
+tilt-heavy-winds = Heavy Winds
+    .description = Strong winds buffet everyone, making physical actions harder.
+tilt-ice = Ice
+    .description = Slippery ground slows movement and makes dodging harder.
+tilt-inferno = Inferno
+    .description = The area is on fire. Anyone caught in it takes fire damage every turn.
+
+short-term = Short-term
+long-term = Long-term
+fulfill = Fulfilled
+notes = Notes
+aspiration-fulfilled = { $aspiration } (fulfilled)
+replace-aspiration = New aspiration
+replace = Replace
+new-aspiration = Aspiration
+add-aspiration = Add aspiration
+history = History
+event-aspiration-fulfilled = Fulfilled "{ $aspiration }": { $beats ->
+    [one] 1 Beat
+   *[other] { $beats } Beats
+}
+
+spend-dice = Spend Willpower: +3 dice
+spend-resistance = Spend Willpower: +2 resistance
+willpower-pending = Spent on the next roll: { $spend }
+regain-vice = Indulge { $anchor }
+regain-virtue = Uphold { $anchor }
+regain-rest = Night's rest
+end-scene = End scene
+end-chapter = End chapter
+event-willpower-spent = { $spend }
+event-willpower-anchor = Regained { $amount } Willpower through { $anchor }
+event-willpower-rest = Regained { $amount } Willpower from a night's rest
+
+spend = Spend
+gain = Gain
+fuel-used = This turn: { $turn }/{ $limit }, this scene: { $scene }
+fuel-limit = Can't spend more than { $limit } { $fuel } per turn.
+fuel-empty = Not enough { $fuel } left.
+fuel-full = { $fuel } is full; the rest is lost.
+
+scene = Scene
+chapter = Chapter
+story = Story
+end-story = End story
+end-of = End of { $end }
+award-aspiration = Fulfilled "{ $aspiration }"
+award-condition = Resolved { $condition }
+dramatic-failures = Dramatic failures
+confirm = Confirm
+event-ended = End of { $end }: { $beats ->
+    [one] 1 Beat
+   *[other] { $beats } Beats
+}, { $healed } healed, { $willpower } Willpower regained
+
+background = Background
+description = Description
+goals = Goals
+journal = Journal
+search-notes = Search entries
+new-entry = New entry
+untitled = Untitled
+date = Date
+title = Title
+close = Close
+
+or = or
+show-all-merits = Show all merits
+merit-requires = { $merit }: requires { $prerequisites }
+prerequisites-lost = Prerequisites no longer met: { $prerequisites }
+prerequisites-unknown = prerequisites not met
+
+status-group = Group
+language-name = Language
+safe-place-location = Location
+contacts-spheres = Spheres of influence
+profession = Profession
+training-skill = Free Skill dot
+use-for-defense = Use for Defense
+
+merit-prerequisites = Prerequisites: { $prerequisites }
+source = Source: { $source }
+
+defense-value = Defense { $defense }
+maneuvers = Maneuvers
+maneuver-locked = Unlocks at { $dots } dots
+in-use = in use
+maneuver-drive-them-back = Drive Them Back
+    .description = Press the attack without regard for your own safety: +1 to attack rolls, -1 Defense while you keep it up.
+maneuver-unrelenting = Unrelenting
+    .description = You're always ready for the next fight: +1 Initiative.
+maneuver-overwhelm = Overwhelm
+    .description = Throw everything into the attack: +2 to attack rolls, but you lose your Defense for the turn.
+maneuver-flow = Flow
+    .description = Ignore penalties from difficult terrain while running.
+maneuver-cat-leap = Cat Leap
+    .description = Roll Dexterity + Athletics when falling; each success reduces the damage by one.
+maneuver-wall-run = Wall Run
+    .description = Run up a wall as part of your movement, rolling Strength + Athletics for extra height.
+maneuver-expert-traceur = Expert Traceur
+    .description = Cross obstacles as part of your move without a roll.
+maneuver-freeflow = Freeflow
+    .description = Move through the environment with no wasted motion: +2 Speed while running.
+maneuver-defensive-driving = Defensive Driving
+    .description = Keep the car moving unpredictably: +1 Defense while driving.
+maneuver-speed-demon = Speed Demon
+    .description = Drive at top speed without taking penalties for it.
+maneuver-drive-by = Drive-by
+    .description = Drive and shoot in the same turn, rolling Dexterity + Firearms.
+maneuver-always-be-closing = Always Be Closing
+    .description = Keep the pitch moving against someone you've only just met.
+maneuver-jargon = Jargon
+    .description = Bury the target in technical terms so they can't follow the argument.
+maneuver-devils-advocate = Devil's Advocate
+    .description = After a failed attempt to persuade, get a second try from another angle.
+maneuver-salting-the-mine = Salting the Mine
+    .description = Set up an opening before the pitch, so the target finds what you want them to.
+maneuver-the-nigerian-scam = The Nigerian Scam
+    .description = Con the target into confirming the lie for you.
+specialties = Specialties
+specialty = Specialty
+add-specialty = Add Specialty
+suggestions = Suggestions
+specialty-creation = Creation
+specialty-experience = Experience
+specialty-merit = Merit
+duplicate-specialty = Duplicate
+specialty-empty = The Specialty needs a name.
+specialty-training = Professional Training has no free Specialty left for this Skill.
+specialty-inactive = no longer granted
+specialty-bonus = Specialty: { $name }
+no-specialty = No Specialty
+other = Other
+
+adjustment = Manual override
+adjustment-named = { $name } (override)
+adjustments = Adjustments
+adjustment-name = Reason
+add-adjustment = Adjust
@@ -0,0 +1,199 @@
+use cofd::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{extras::CharacterExtras, history::Event, i18n, i18n::Translate};
+
+/// Most characters have three Aspirations at a time.
+pub const MAX_ACTIVE: usize = 3;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Term {
+	#[default]
+	Short,
+	Long,
+}
+
+impl Term {
+	pub fn all() -> [Term; 2] {
+		[Self::Short, Self::Long]
+	}
+}
+
+impl Translate for Term {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(match self {
+			Self::Short => "short-term",
+			Self::Long => "long-term",
+		})
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Status {
+	#[default]
+	Active,
+	Fulfilled,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Aspiration {
+	pub text: String,
+	pub term: Term,
+	pub status: Status,
+	pub notes: String,
+}
+
+impl Aspiration {
+	pub fn new(text: String) -> Self {
+		Self {
+			text,
+			..Default::default()
+		}
+	}
+
+	/// Beats for fulfilling it. Long-term Aspirations are worth two.
+	pub fn beats(&self) -> u16 {
+		match self.term {
+			Term::Short => 1,
+			Term::Long => 2,
+		}
+	}
+
+	pub fn is_active(&self) -> bool {
+		self.status == Status::Active
+	}
+}
+
+/// Whether there's room for another Aspiration. Fulfilled ones waiting to be replaced don't
+/// count towards [`MAX_ACTIVE`].
+pub fn has_room(aspirations: &[Aspiration]) -> bool {
+	aspirations
+		.iter()
+		.filter(|aspiration| aspiration.is_active())
+		.count()
+		< MAX_ACTIVE
+}
+
+/// Marks an active Aspiration fulfilled and awards its Beats, returning how many.
+pub fn fulfill(character: &mut Character, extras: &mut CharacterExtras, i: usize) -> u16 {
+	let Some(aspiration) = extras.aspirations.get_mut(i) else {
+		return 0;
+	};
+	if !aspiration.is_active() {
+		return 0;
+	}
+
+	let beats = aspiration.beats();
+	aspiration.status = Status::Fulfilled;
+	character.beats += beats;
+
+	extras.history.push(Event::AspirationFulfilled {
+		aspiration: aspiration.text.clone(),
+		beats,
+	});
+	beats
+}
+
+/// Characters saved before Aspirations were tracked only have the plain strings.
+pub fn migrate(character: &Character, extras: &mut CharacterExtras) {
+	if extras.aspirations.is_empty() {
+		extras.aspirations = character
+			.aspirations
+			.iter()
+			.cloned()
+			.map(Aspiration::new)
+			.collect();
+	}
+}
+
+/// Keeps `cofd::Character`'s own list in step with the active Aspirations.
+pub fn sync(character: &mut Character, extras: &CharacterExtras) {
+	character.aspirations = extras
+		.aspirations
+		.iter()
+		.filter(|aspiration| aspiration.is_active())
+		.map(|aspiration| aspiration.text.clone())
+		.collect();
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn extras(aspirations: &[(&str, Term)]) -> CharacterExtras {
+		CharacterExtras {
+			aspirations: aspirations
+				.iter()
+				.map(|(text, term)| Aspiration {
+					term: *term,
+					..Aspiration::new((*text).to_string())
+				})
+				.collect(),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn long_term_aspirations_give_two_beats() {
+		let mut character = Character::default();
+		let mut extras = extras(&[("Get a job", Term::Short), ("Buy a house", Term::Long)]);
+
+		assert_eq!(fulfill(&mut character, &mut extras, 0), 1);
+		assert_eq!(fulfill(&mut character, &mut extras, 1), 2);
+		assert_eq!(character.beats, 3);
+		assert_eq!(
+			extras.history.last(),
+			Some(&Event::AspirationFulfilled {
+				aspiration: "Buy a house".to_string(),
+				beats: 2,
+			})
+		);
+	}
+
+	#[test]
+	fn fulfilling_twice_does_nothing() {
+		let mut character = Character::default();
+		let mut extras = extras(&[("Get a job", Term::Short)]);
+
+		fulfill(&mut character, &mut extras, 0);
+		assert_eq!(fulfill(&mut character, &mut extras, 0), 0);
+		assert_eq!(fulfill(&mut character, &mut extras, 5), 0);
+		assert_eq!(character.beats, 1);
+		assert_eq!(extras.history.len(), 1);
+	}
+
+	#[test]
+	fn migrate_keeps_existing_aspirations() {
+		let mut character = Character::default();
+		character.aspirations = vec!["Old".to_string()];
+
+		let mut empty = CharacterExtras::default();
+		migrate(&character, &mut empty);
+		assert_eq!(empty.aspirations, vec![Aspiration::new("Old".to_string())]);
+
+		let mut tracked = extras(&[("Buy a house", Term::Long)]);
+		migrate(&character, &mut tracked);
+		assert_eq!(tracked, extras(&[("Buy a house", Term::Long)]));
+	}
+
+	#[test]
+	fn sync_lists_only_active_aspirations() {
+		let mut character = Character::default();
+		let mut extras = extras(&[("Get a job", Term::Short), ("Buy a house", Term::Long)]);
+		fulfill(&mut character, &mut extras, 0);
+
+		sync(&mut character, &extras);
+		assert_eq!(character.aspirations, vec!["Buy a house".to_string()]);
+	}
+
+	#[test]
+	fn fulfilled_aspirations_leave_room() {
+		let mut character = Character::default();
+		let mut extras = extras(&[("A", Term::Short), ("B", Term::Short), ("C", Term::Short)]);
+		assert!(!has_room(&extras.aspirations));
+
+		fulfill(&mut character, &mut extras, 1);
+		assert!(has_room(&extras.aspirations));
+	}
+}
@@ -0,0 +1,155 @@
+use cofd::{prelude::*, splat::Splat};
+
+use crate::i18n::Translate;
+
+/// The cap everyone has until their power climbs past it, and that Renown and Haunts never
+/// go past.
+const MORTAL: u16 = 5;
+
+/// Attributes, Skills and Disciplines by Blood Potency, Gnosis, Primal Urge, Wyrd or Synergy:
+/// 5 through power 5, then matching it up to 10.
+const TRAITS: [u16; 11] = [5, 5, 5, 5, 5, 5, 6, 7, 8, 9, 10];
+/// Arcana by Gnosis.
+const ARCANA: [u16; 11] = [2, 2, 3, 3, 4, 4, 5, 5, 5, 5, 5];
+
+/// How many dots go in a row before the rest wrap onto the next.
+pub const ROW: u16 = 5;
+
+/// What a cap applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+	Attribute,
+	Skill,
+	/// The splat's own abilities: Disciplines, Renown, Arcana or Haunts.
+	Ability,
+}
+
+/// The most dots a trait of the kind can have in the splat at the given power.
+pub fn max(splat: &Splat, power: u16, kind: Kind) -> u16 {
+	if splat.supernatural_tolerance().is_none() {
+		return MORTAL;
+	}
+
+	let power = usize::from(power).min(TRAITS.len() - 1);
+	match (splat, kind) {
+		(Splat::Werewolf(..) | Splat::Bound(..), Kind::Ability) => MORTAL,
+		(Splat::Mage(..), Kind::Ability) => ARCANA[power],
+		_ => TRAITS[power],
+	}
+}
+
+/// The character's cap for the kind of trait.
+pub fn of(character: &Character, kind: Kind) -> u16 {
+	max(&character.splat, character.power, kind)
+}
+
+pub fn exceeds(character: &Character, kind: Kind, val: u16) -> bool {
+	val > of(character, kind)
+}
+
+/// The traits rated above their cap, such as after power has been lowered, with the cap each
+/// one is over.
+pub fn over(character: &Character) -> Vec<(String, u16)> {
+	let mut vec = Vec::new();
+
+	for category in [
+		TraitCategory::Mental,
+		TraitCategory::Physical,
+		TraitCategory::Social,
+	] {
+		for attr in Attribute::get_by_category(category) {
+			if exceeds(
+				character,
+				Kind::Attribute,
+				character.base_attributes().get(&attr),
+			) {
+				vec.push((attr.translated(), of(character, Kind::Attribute)));
+			}
+		}
+	}
+
+	for skill in Skill::all() {
+		if exceeds(character, Kind::Skill, character.base_skills().get(skill)) {
+			vec.push((skill.translated(), of(character, Kind::Skill)));
+		}
+	}
+
+	for (ability, val) in &character.abilities {
+		if exceeds(character, Kind::Ability, *val) {
+			vec.push((ability.translated(), of(character, Kind::Ability)));
+		}
+	}
+
+	vec
+}
+
+#[cfg(test)]
+mod tests {
+	use cofd::splat::{
+		changeling::{Changeling, Seeming},
+		mage::{Mage, Path},
+		vampire::{Clan, Vampire},
+		werewolf::Werewolf,
+	};
+
+	use super::*;
+
+	fn splat(splat: impl Into<Splat>) -> Splat {
+		Character::builder().with_splat(splat).build().splat
+	}
+
+	fn caps(splat: &Splat, power: u16) -> [u16; 3] {
+		[
+			max(splat, power, Kind::Attribute),
+			max(splat, power, Kind::Skill),
+			max(splat, power, Kind::Ability),
+		]
+	}
+
+	#[test]
+	fn mortals_stay_at_five() {
+		let mortal = Character::default().splat;
+		assert_eq!(caps(&mortal, 0), [5, 5, 5]);
+		assert_eq!(caps(&mortal, 10), [5, 5, 5]);
+	}
+
+	#[test]
+	fn vampires_follow_blood_potency() {
+		let vampire = splat(Vampire::new(Clan::Ventrue, None, None));
+		assert_eq!(caps(&vampire, 1), [5, 5, 5]);
+		assert_eq!(caps(&vampire, 5), [5, 5, 5]);
+		assert_eq!(caps(&vampire, 6), [6, 6, 6]);
+		assert_eq!(caps(&vampire, 10), [10, 10, 10]);
+	}
+
+	#[test]
+	fn renown_stays_at_five() {
+		let werewolf = splat(Werewolf::new());
+		assert_eq!(caps(&werewolf, 1), [5, 5, 5]);
+		assert_eq!(caps(&werewolf, 8), [8, 8, 5]);
+		assert_eq!(caps(&werewolf, 10), [10, 10, 5]);
+	}
+
+	#[test]
+	fn arcana_follow_gnosis() {
+		let mage = splat(Mage::new(Path::Mastigos));
+		assert_eq!(caps(&mage, 1), [5, 5, 2]);
+		assert_eq!(caps(&mage, 2), [5, 5, 3]);
+		assert_eq!(caps(&mage, 4), [5, 5, 4]);
+		assert_eq!(caps(&mage, 6), [6, 6, 5]);
+		assert_eq!(caps(&mage, 10), [10, 10, 5]);
+	}
+
+	#[test]
+	fn changelings_follow_wyrd() {
+		let changeling = splat(Changeling::new(Seeming::Wizened));
+		assert_eq!(caps(&changeling, 3), [5, 5, 5]);
+		assert_eq!(caps(&changeling, 7), [7, 7, 7]);
+	}
+
+	#[test]
+	fn power_past_ten_is_capped() {
+		let vampire = splat(Vampire::new(Clan::Ventrue, None, None));
+		assert_eq!(caps(&vampire, 12), [10, 10, 10]);
+	}
+}
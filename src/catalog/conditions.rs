@@ -0,0 +1,32 @@
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use super::Effect;
+use crate::i18n::{self, Translate};
+
+pub static CONDITIONS: Lazy<Vec<ConditionEntry>> = Lazy::new(|| super::load("conditions.ron"));
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ConditionEntry {
+	pub key: String,
+	pub persistent: bool,
+	pub effects: Vec<Effect>,
+}
+
+impl ConditionEntry {
+	pub fn description(&self) -> String {
+		i18n::LANGUAGE_LOADER.get_attr(&self.key, "description")
+	}
+}
+
+impl Translate for ConditionEntry {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(&self.key)
+	}
+}
+
+/// Looks up a condition as stored on the character. Anything else is a custom condition.
+pub fn get(key: &str) -> Option<&'static ConditionEntry> {
+	CONDITIONS.iter().find(|entry| entry.key == key)
+}
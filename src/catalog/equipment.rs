@@ -0,0 +1,170 @@
+use cofd::{character::Weapon, prelude::*};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use crate::{
+	i18n::{self, Translate},
+	items::{Armor, Item},
+};
+
+pub static EQUIPMENT: Lazy<EquipmentCatalog> = Lazy::new(|| super::load("equipment.ron"));
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EquipmentCatalog {
+	pub weapons: Vec<WeaponEntry>,
+	pub armor: Vec<ArmorEntry>,
+	pub gear: Vec<GearEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeaponEntry {
+	pub key: String,
+	pub dice_pool: String,
+	pub damage: String,
+	#[serde(default)]
+	pub range: String,
+	#[serde(default)]
+	pub initiative: i16,
+	pub size: u16,
+	#[serde(default)]
+	pub availability: u16,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ArmorEntry {
+	pub key: String,
+	pub general: u16,
+	pub ballistic: u16,
+	pub strength: u16,
+	pub defense: u16,
+	pub speed: u16,
+	pub coverage: String,
+	pub availability: u16,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GearEntry {
+	pub key: String,
+	pub size: u16,
+	pub durability: u16,
+	pub structure: u16,
+	pub availability: u16,
+	pub skill: Option<Skill>,
+	pub bonus: i16,
+}
+
+impl Translate for WeaponEntry {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(&self.key)
+	}
+}
+
+impl Translate for ArmorEntry {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(&self.key)
+	}
+}
+
+impl Translate for GearEntry {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(&self.key)
+	}
+}
+
+impl WeaponEntry {
+	pub fn weapon(&self) -> Weapon {
+		Weapon {
+			name: self.translated(),
+			dice_pool: self.dice_pool.clone(),
+			damage: self.damage.clone(),
+			range: self.range.clone(),
+			initative: self.initiative,
+			size: self.size,
+		}
+	}
+}
+
+impl ArmorEntry {
+	pub fn armor(&self) -> Armor {
+		Armor {
+			name: self.translated(),
+			general: self.general,
+			ballistic: self.ballistic,
+			strength: self.strength,
+			defense: self.defense,
+			speed: self.speed,
+			coverage: self.coverage.clone(),
+			equipped: false,
+		}
+	}
+}
+
+impl GearEntry {
+	pub fn item(&self) -> Item {
+		Item {
+			name: self.translated(),
+			size: self.size,
+			durability: self.durability,
+			structure: self.structure,
+			availability: self.availability,
+			skill: self.skill,
+			bonus: self.bonus,
+			..Default::default()
+		}
+	}
+}
+
+/// One entry of the catalog, by kind and index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entry {
+	Weapon(usize),
+	Armor(usize),
+	Gear(usize),
+}
+
+impl EquipmentCatalog {
+	pub fn name(&self, entry: Entry) -> Option<String> {
+		match entry {
+			Entry::Weapon(i) => self.weapons.get(i).map(Translate::translated),
+			Entry::Armor(i) => self.armor.get(i).map(Translate::translated),
+			Entry::Gear(i) => self.gear.get(i).map(Translate::translated),
+		}
+	}
+
+	pub fn availability(&self, entry: Entry) -> Option<u16> {
+		match entry {
+			Entry::Weapon(i) => self.weapons.get(i).map(|entry| entry.availability),
+			Entry::Armor(i) => self.armor.get(i).map(|entry| entry.availability),
+			Entry::Gear(i) => self.gear.get(i).map(|entry| entry.availability),
+		}
+	}
+
+	/// Every entry whose localized name contains `query`, ignoring case.
+	pub fn search(&self, query: &str) -> Vec<(Entry, String)> {
+		let query = query.to_lowercase();
+
+		let weapons = self
+			.weapons
+			.iter()
+			.enumerate()
+			.map(|(i, entry)| (Entry::Weapon(i), entry.translated()));
+		let armor = self
+			.armor
+			.iter()
+			.enumerate()
+			.map(|(i, entry)| (Entry::Armor(i), entry.translated()));
+		let gear = self
+			.gear
+			.iter()
+			.enumerate()
+			.map(|(i, entry)| (Entry::Gear(i), entry.translated()));
+
+		weapons
+			.chain(armor)
+			.chain(gear)
+			.filter(|(_, name)| name.to_lowercase().contains(&query))
+			.collect()
+	}
+}
@@ -0,0 +1,127 @@
+use cofd::{prelude::*, splat::Merit};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use crate::{fl, i18n, i18n::Translate};
+
+pub static MERITS: Lazy<Vec<MeritEntry>> = Lazy::new(|| super::load("merits.ron"));
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum Prerequisite {
+	Attribute(Attribute, u16),
+	Skill(Skill, u16),
+	/// Another Merit, by its name key, at a rating.
+	Merit(String, u16),
+	/// A splat, by its name key.
+	Splat(String),
+	/// Any one of these.
+	Any(Vec<Prerequisite>),
+}
+
+impl Prerequisite {
+	pub fn is_met(&self, character: &Character) -> bool {
+		match self {
+			Self::Attribute(attr, val) => character.attributes().get(attr) >= *val,
+			Self::Skill(skill, val) => character.skills().get(*skill) >= *val,
+			Self::Merit(key, val) => character
+				.merits
+				.iter()
+				.any(|(merit, rating)| merit.name() == key && rating >= val),
+			Self::Splat(key) => character.splat.name() == key,
+			Self::Any(vec) => vec
+				.iter()
+				.any(|prerequisite| prerequisite.is_met(character)),
+		}
+	}
+}
+
+impl Translate for Prerequisite {
+	fn translated(&self) -> String {
+		match self {
+			Self::Attribute(attr, val) => format!("{} {val}", attr.translated()),
+			Self::Skill(skill, val) => format!("{} {val}", skill.translated()),
+			Self::Merit(key, val) => format!("{} {val}", i18n::LANGUAGE_LOADER.get(key)),
+			Self::Splat(key) => i18n::LANGUAGE_LOADER.get(key),
+			Self::Any(vec) => vec
+				.iter()
+				.map(Translate::translated)
+				.collect::<Vec<_>>()
+				.join(&format!(" {} ", fl!("or"))),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct MeritEntry {
+	pub key: String,
+	/// Dots the Merit can be bought at, lowest first.
+	pub ratings: Vec<u16>,
+	pub prerequisites: Vec<Prerequisite>,
+}
+
+pub fn get(merit: &Merit) -> Option<&'static MeritEntry> {
+	MERITS.iter().find(|entry| entry.key == merit.name())
+}
+
+/// Everything the Merit requires, whether the character has it or not.
+pub fn prerequisites(merit: &Merit) -> Vec<String> {
+	get(merit)
+		.map(|entry| {
+			entry
+				.prerequisites
+				.iter()
+				.map(Translate::translated)
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
+/// Ratings the Merit can be bought at. Merits the catalog doesn't cover allow anything up to 5.
+pub fn ratings(merit: &Merit) -> Vec<u16> {
+	get(merit)
+		.filter(|entry| !entry.ratings.is_empty())
+		.map_or_else(|| (1..=5).collect(), |entry| entry.ratings.clone())
+}
+
+/// Moves a rating down to the nearest one the Merit allows, or up to its lowest one.
+pub fn snap(merit: &Merit, val: u16) -> u16 {
+	if val == 0 {
+		return 0;
+	}
+
+	let ratings = ratings(merit);
+	ratings
+		.iter()
+		.rev()
+		.find(|rating| **rating <= val)
+		.or_else(|| ratings.first())
+		.copied()
+		.unwrap_or(val)
+}
+
+/// Why the character can't take the Merit, if they can't.
+///
+/// Prerequisites come from the Merit catalog. cofd's own check still applies to Merits the
+/// catalog doesn't cover, which gets a generic reason.
+pub fn unmet(merit: &Merit, character: &Character) -> Vec<String> {
+	let mut vec: Vec<String> = get(merit)
+		.map(|entry| {
+			entry
+				.prerequisites
+				.iter()
+				.filter(|prerequisite| !prerequisite.is_met(character))
+				.map(Translate::translated)
+				.collect()
+		})
+		.unwrap_or_default();
+
+	let attributes = &character.attributes();
+	let skills = &character.skills();
+
+	if vec.is_empty() && !merit.is_available(character, attributes, skills) {
+		vec.push(fl!("prerequisites-unknown"));
+	}
+
+	vec
+}
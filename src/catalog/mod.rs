@@ -0,0 +1,58 @@
+use rust_embed::RustEmbed;
+use serde::Deserialize;
+
+use crate::{fl, i18n::Translate, modifiers::Target};
+
+pub mod conditions;
+pub mod equipment;
+pub mod merits;
+pub mod styles;
+pub mod tilts;
+
+pub use conditions::CONDITIONS;
+pub use equipment::EQUIPMENT;
+pub use tilts::TILTS;
+
+#[derive(RustEmbed)]
+#[folder = "catalog"]
+struct Catalogs;
+
+/// Reads a bundled catalog. These ship with the app, so a broken one is a bug.
+fn load<T: for<'a> Deserialize<'a>>(name: &str) -> T {
+	let file = Catalogs::get(name).unwrap_or_else(|| panic!("Missing catalog {name}"));
+
+	ron::de::from_bytes(&file.data).unwrap_or_else(|err| panic!("Invalid catalog {name}: {err}"))
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum Scale {
+	/// Adds `value` as is.
+	#[default]
+	Flat,
+	/// Takes away half of the trait, e.g. halved Defense.
+	Half,
+	/// Takes away all of the trait.
+	All,
+}
+
+/// A mechanical effect a Condition or Tilt has while it's active.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Effect {
+	pub target: Target,
+	#[serde(default)]
+	pub value: i16,
+	#[serde(default)]
+	pub scale: Scale,
+}
+
+impl Translate for Effect {
+	fn translated(&self) -> String {
+		let target = self.target.translated();
+
+		match self.scale {
+			Scale::Flat => format!("{:+} {target}", self.value),
+			Scale::Half => fl!("effect-halved", target = target),
+			Scale::All => fl!("effect-lost", target = target),
+		}
+	}
+}
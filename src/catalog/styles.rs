@@ -0,0 +1,63 @@
+use cofd::{prelude::*, splat::Merit};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use super::Effect;
+use crate::i18n::{self, Translate};
+
+pub static STYLES: Lazy<Vec<StyleEntry>> = Lazy::new(|| super::load("styles.ron"));
+
+/// A Fighting Style, or another Style Merit, and the maneuvers each of its dots unlocks.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct StyleEntry {
+	/// The Merit's name key.
+	pub key: String,
+	pub maneuvers: Vec<Maneuver>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Maneuver {
+	pub key: String,
+	/// The rating the Style needs for this maneuver.
+	pub dots: u16,
+	/// Dice pool expression, for maneuvers that are rolled.
+	pub pool: Option<String>,
+	/// Passive maneuvers always apply; the others only while they're in use.
+	pub passive: bool,
+	pub effects: Vec<Effect>,
+}
+
+impl Maneuver {
+	pub fn description(&self) -> String {
+		i18n::LANGUAGE_LOADER.get_attr(&self.key, "description")
+	}
+}
+
+impl Translate for Maneuver {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(&self.key)
+	}
+}
+
+pub fn get(merit: &Merit) -> Option<&'static StyleEntry> {
+	STYLES.iter().find(|entry| entry.key == merit.name())
+}
+
+/// Maneuvers the character's Style Merits give them at their current ratings.
+pub fn unlocked(character: &Character) -> Vec<&'static Maneuver> {
+	character
+		.merits
+		.iter()
+		.filter_map(|(merit, val)| {
+			get(merit).map(|style| {
+				style
+					.maneuvers
+					.iter()
+					.filter(move |maneuver| maneuver.dots <= *val)
+			})
+		})
+		.flatten()
+		.collect()
+}
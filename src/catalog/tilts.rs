@@ -0,0 +1,32 @@
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use super::Effect;
+use crate::i18n::{self, Translate};
+
+pub static TILTS: Lazy<Vec<TiltEntry>> = Lazy::new(|| super::load("tilts.ron"));
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TiltEntry {
+	pub key: String,
+	/// Environmental Tilts affect everyone in the scene rather than a single character.
+	pub environmental: bool,
+	pub effects: Vec<Effect>,
+}
+
+impl TiltEntry {
+	pub fn description(&self) -> String {
+		i18n::LANGUAGE_LOADER.get_attr(&self.key, "description")
+	}
+}
+
+impl Translate for TiltEntry {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(&self.key)
+	}
+}
+
+pub fn get(key: &str) -> Option<&'static TiltEntry> {
+	TILTS.iter().find(|entry| entry.key == key)
+}
@@ -0,0 +1,205 @@
+use cofd::prelude::*;
+use iced::{
+	theme,
+	widget::{button, column, pick_list, row, text, text_input, Column},
+	Alignment, Color, Length,
+};
+
+use crate::{
+	aspirations::{self, Aspiration, Term},
+	extras::CharacterExtras,
+	fl,
+	i18n::Translated,
+	Element, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
+};
+
+#[derive(Debug, Clone)]
+pub struct AspirationsComponent {
+	/// A new Aspiration being typed in.
+	draft: String,
+	/// The replacement being typed in for a fulfilled Aspiration.
+	replacement: Option<(usize, String)>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+	Draft(String),
+	New,
+	Text(usize, String),
+	Term(usize, Term),
+	Notes(usize, String),
+	/// Awards the Beats and marks it to be replaced.
+	Fulfill(usize),
+	ReplacementDraft(usize, String),
+	/// Turns a fulfilled Aspiration into the replacement typed in for it.
+	Replace(usize),
+	Remove(usize),
+}
+
+impl AspirationsComponent {
+	pub fn new() -> Self {
+		Self {
+			draft: String::new(),
+			replacement: None,
+		}
+	}
+
+	pub fn update(
+		&mut self,
+		message: Message,
+		character: &mut Character,
+		extras: &mut CharacterExtras,
+	) {
+		let list = &mut extras.aspirations;
+
+		match message {
+			Message::Draft(text) => self.draft = text,
+			Message::New => {
+				let text = self.draft.trim();
+				if !text.is_empty() && aspirations::has_room(list) {
+					list.push(Aspiration::new(text.to_string()));
+					self.draft.clear();
+				}
+			}
+			Message::Text(i, text) => {
+				if text.is_empty() {
+					if i < list.len() {
+						list.remove(i);
+					}
+				} else if let Some(aspiration) = list.get_mut(i) {
+					aspiration.text = text;
+				}
+			}
+			Message::Term(i, term) => {
+				if let Some(aspiration) = list.get_mut(i) {
+					aspiration.term = term;
+				}
+			}
+			Message::Notes(i, notes) => {
+				if let Some(aspiration) = list.get_mut(i) {
+					aspiration.notes = notes;
+				}
+			}
+			Message::Fulfill(i) => {
+				aspirations::fulfill(character, extras, i);
+			}
+			Message::ReplacementDraft(i, text) => self.replacement = Some((i, text)),
+			Message::Replace(i) => {
+				if let Some((j, text)) = &self.replacement
+					&& *j == i && !text.trim().is_empty()
+					&& let Some(aspiration) = list.get_mut(i)
+				{
+					*aspiration = Aspiration {
+						term: aspiration.term,
+						..Aspiration::new(text.trim().to_string())
+					};
+					self.replacement = None;
+				}
+			}
+			Message::Remove(i) => {
+				if i < list.len() {
+					list.remove(i);
+					self.replacement = None;
+				}
+			}
+		}
+
+		aspirations::sync(character, extras);
+	}
+
+	pub fn view(&self, extras: &CharacterExtras) -> Element<Message> {
+		let mut col = Column::new()
+			.spacing(TITLE_SPACING)
+			.align_items(Alignment::Center)
+			.push(text(fl!("aspirations")).size(H3_SIZE));
+
+		for (i, aspiration) in extras.aspirations.iter().enumerate() {
+			let remove = button(text("x"))
+				.padding(INPUT_PADDING)
+				.on_press(Message::Remove(i));
+
+			if aspiration.is_active() {
+				let terms: Vec<Translated<Term>> =
+					Term::all().into_iter().map(Into::into).collect();
+
+				col = col.push(
+					column![
+						row![
+							text_input("", &aspiration.text)
+								.on_input(move |val| Message::Text(i, val))
+								.padding(INPUT_PADDING),
+							pick_list(
+								terms,
+								Some::<Translated<Term>>(aspiration.term.into()),
+								move |val| { Message::Term(i, *val) }
+							)
+							.padding(INPUT_PADDING),
+							button(text(fl!("fulfill")))
+								.padding(INPUT_PADDING)
+								.on_press(Message::Fulfill(i)),
+							remove,
+						]
+						.spacing(3)
+						.align_items(Alignment::Center),
+						text_input(&fl!("notes"), &aspiration.notes)
+							.on_input(move |val| Message::Notes(i, val))
+							.padding(INPUT_PADDING),
+					]
+					.spacing(3),
+				);
+			} else {
+				col = col.push(
+					column![
+						row![
+							text(fl!(
+								"aspiration-fulfilled",
+								aspiration = aspiration.text.as_str()
+							))
+							.style(theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5)))
+							.width(Length::Fill),
+							remove,
+						]
+						.spacing(3)
+						.align_items(Alignment::Center),
+						row![
+							text_input(
+								&fl!("replace-aspiration"),
+								self.replacement
+									.as_ref()
+									.filter(|(j, _)| *j == i)
+									.map_or("", |(_, text)| text.as_str()),
+							)
+							.on_input(move |val| Message::ReplacementDraft(i, val))
+							.on_submit(Message::Replace(i))
+							.padding(INPUT_PADDING),
+							button(text(fl!("replace")))
+								.padding(INPUT_PADDING)
+								.on_press(Message::Replace(i)),
+						]
+						.spacing(3)
+						.align_items(Alignment::Center),
+					]
+					.spacing(3),
+				);
+			}
+		}
+
+		if aspirations::has_room(&extras.aspirations) {
+			col = col.push(
+				row![
+					text_input(&fl!("new-aspiration"), &self.draft)
+						.on_input(Message::Draft)
+						.on_submit(Message::New)
+						.padding(INPUT_PADDING),
+					button(text(fl!("add-aspiration")))
+						.padding(INPUT_PADDING)
+						.on_press(Message::New),
+				]
+				.spacing(3)
+				.align_items(Alignment::Center),
+			);
+		}
+
+		col.into()
+	}
+}
@@ -3,13 +3,17 @@ use cofd::{
 	prelude::{TraitCategory, *},
 };
 use iced::{
+	theme,
 	widget::{column, row, text, Column},
-	Alignment, Length,
+	Alignment, Color, Length,
 };
 
+use super::sources;
 use crate::{
+	caps::{self, Kind},
 	fl,
 	i18n::Translate,
+	modifiers::Breakdown,
 	widget::dots::{Shape, SheetDots},
 	Element, H2_SIZE, TITLE_SPACING,
 };
@@ -30,7 +34,7 @@ impl AttributeBar {
 		*character.base_attributes_mut().get_mut(&attr) = val;
 	}
 
-	pub fn view(&self, character: &Character) -> Element<Message> {
+	pub fn view(&self, character: &Character, breakdown: &Breakdown) -> Element<Message> {
 		column![
 			text(fl!("attributes")).size(H2_SIZE),
 			row![
@@ -42,9 +46,9 @@ impl AttributeBar {
 				.spacing(3)
 				.width(Length::Fill)
 				.align_items(Alignment::End),
-				self.mk_attr_col(character, TraitCategory::Mental),
-				self.mk_attr_col(character, TraitCategory::Physical),
-				self.mk_attr_col(character, TraitCategory::Social),
+				self.mk_attr_col(character, breakdown, TraitCategory::Mental),
+				self.mk_attr_col(character, breakdown, TraitCategory::Physical),
+				self.mk_attr_col(character, breakdown, TraitCategory::Social),
 				column![].width(Length::Fill)
 			]
 			.spacing(10)
@@ -54,7 +58,12 @@ impl AttributeBar {
 		.into()
 	}
 
-	fn mk_attr_col(&self, character: &Character, category: TraitCategory) -> Element<Message> {
+	fn mk_attr_col(
+		&self,
+		character: &Character,
+		breakdown: &Breakdown,
+		category: TraitCategory,
+	) -> Element<Message> {
 		let mut col1 = Column::new().spacing(3);
 		let mut col2 = Column::new()
 			.spacing(5)
@@ -67,13 +76,24 @@ impl AttributeBar {
 			let val = character._modified(ModifierTarget::BaseAttribute(attr));
 			let mod_ = val - v;
 
-			col1 = col1.push(text(attr.translated()));
+			let parts = breakdown.parts(character, |character| {
+				i32::from(character._modified(ModifierTarget::BaseAttribute(attr)))
+			});
+
+			col1 = col1.push(sources(
+				text(attr.translated()).style(if caps::exceeds(character, Kind::Attribute, v) {
+					theme::Text::Color(Color::from_rgb(0.8, 0.0, 0.0))
+				} else {
+					theme::Text::Default
+				}),
+				&parts,
+			));
 			col2 = col2.push(SheetDots::new(
 				val,
 				1 + mod_,
-				5,
+				caps::of(character, Kind::Attribute) + mod_,
 				Shape::Dots,
-				None,
+				Some(caps::ROW),
 				move |val| Message(val - mod_, attr),
 			));
 		}
@@ -0,0 +1,163 @@
+use std::fmt::{self, Display};
+
+use cofd::prelude::*;
+use iced::{
+	theme,
+	widget::{button, column, pick_list, row, text, text_input, Column},
+	Alignment, Color, Length,
+};
+
+use crate::{
+	catalog::{conditions, CONDITIONS},
+	fl,
+	i18n::Translate,
+	Element, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionPick(String, String);
+
+impl Display for ConditionPick {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.1)
+	}
+}
+
+/// Conditions from the bundled catalog, along with custom ones typed in by hand.
+#[derive(Debug, Clone)]
+pub struct ConditionsComponent {
+	/// A custom condition being typed in, added on submit.
+	custom: String,
+}
+
+#[derive(Clone)]
+pub enum Message {
+	Add(ConditionPick),
+	CustomChanged(usize, String),
+	Custom(String),
+	AddCustom,
+	/// Removes the condition and awards a Beat.
+	Resolve(usize),
+	/// A persistent condition giving its Beat without being resolved.
+	TakeBeat,
+}
+
+impl ConditionsComponent {
+	pub fn new() -> Self {
+		Self {
+			custom: String::new(),
+		}
+	}
+
+	pub fn update(&mut self, message: Message, character: &mut Character) {
+		match message {
+			Message::Add(ConditionPick(key, _)) => {
+				if !character.conditions.contains(&key) {
+					character.conditions.push(key);
+				}
+			}
+			Message::CustomChanged(i, val) => {
+				if i < character.conditions.len() {
+					if val.is_empty() {
+						character.conditions.remove(i);
+					} else {
+						character.conditions[i] = val;
+					}
+				}
+			}
+			Message::Custom(val) => self.custom = val,
+			Message::AddCustom => {
+				let custom = self.custom.trim();
+				if !custom.is_empty() && !character.conditions.iter().any(|c| c == custom) {
+					character.conditions.push(custom.to_string());
+				}
+				self.custom.clear();
+			}
+			Message::Resolve(i) => {
+				if i < character.conditions.len() {
+					character.conditions.remove(i);
+					character.beats += 1;
+				}
+			}
+			Message::TakeBeat => character.beats += 1,
+		}
+	}
+
+	pub fn view(&self, character: &Character) -> Element<Message> {
+		let mut col = Column::new()
+			.spacing(TITLE_SPACING)
+			.align_items(Alignment::Center)
+			.push(text(fl!("conditions")).size(H3_SIZE));
+
+		for (i, key) in character.conditions.iter().enumerate() {
+			let resolve = button(text(fl!("resolve-condition")))
+				.padding(INPUT_PADDING)
+				.on_press(Message::Resolve(i));
+
+			if let Some(condition) = conditions::get(key) {
+				let mut title = row![text(condition.translated()).width(Length::Fill)]
+					.spacing(3)
+					.align_items(Alignment::Center);
+
+				if condition.persistent {
+					title = title.push(
+						text(fl!("persistent"))
+							.style(theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+					);
+					title = title.push(
+						button(text(fl!("take-beat")))
+							.padding(INPUT_PADDING)
+							.on_press(Message::TakeBeat),
+					);
+				}
+
+				let mut entry =
+					column![title.push(resolve), text(condition.description())].spacing(3);
+
+				for effect in &condition.effects {
+					entry = entry.push(text(effect.translated()));
+				}
+
+				col = col.push(entry);
+			} else {
+				col = col.push(
+					row![
+						text_input("", key)
+							.on_input(move |val| Message::CustomChanged(i, val))
+							.padding(INPUT_PADDING),
+						resolve
+					]
+					.spacing(3)
+					.align_items(Alignment::Center),
+				);
+			}
+		}
+
+		let catalog: Vec<ConditionPick> = CONDITIONS
+			.iter()
+			.filter(|condition| !character.conditions.contains(&condition.key))
+			.map(|condition| ConditionPick(condition.key.clone(), condition.translated()))
+			.collect();
+
+		col.push(
+			row![
+				text_input(&fl!("custom-condition"), &self.custom)
+					.on_input(Message::Custom)
+					.on_submit(Message::AddCustom)
+					.padding(INPUT_PADDING),
+				button(text(fl!("add-condition")))
+					.padding(INPUT_PADDING)
+					.on_press(Message::AddCustom),
+			]
+			.spacing(3)
+			.align_items(Alignment::Center),
+		)
+		.push(
+			pick_list(catalog, None::<ConditionPick>, Message::Add)
+				.placeholder(fl!("add-condition"))
+				.padding(INPUT_PADDING)
+				.width(Length::Fill),
+		)
+		.into()
+	}
+}
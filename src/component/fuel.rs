@@ -0,0 +1,121 @@
+use cofd::prelude::*;
+use iced::{
+	theme,
+	widget::{button, column, row, text, text_input},
+	Alignment, Color, Length,
+};
+
+use crate::{
+	extras::CharacterExtras,
+	fl,
+	fuel::{self, Error},
+	i18n::Translate,
+	widget::dots::{Shape, SheetDots},
+	Element, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
+};
+
+#[derive(Debug, Clone)]
+pub struct FuelComponent {
+	amount: u16,
+	warning: Option<Error>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Message {
+	FuelChanged(u16),
+	Amount(u16),
+	Spend,
+	Gain,
+	NextTurn,
+}
+
+impl FuelComponent {
+	pub fn new() -> Self {
+		Self {
+			amount: 1,
+			warning: None,
+		}
+	}
+
+	pub fn update(
+		&mut self,
+		message: Message,
+		character: &mut Character,
+		extras: &mut CharacterExtras,
+	) {
+		self.warning = None;
+
+		match message {
+			Message::FuelChanged(fuel) => character.fuel = fuel,
+			Message::Amount(amount) => self.amount = amount,
+			Message::Spend => {
+				self.warning = fuel::spend(character, &mut extras.session.fuel, self.amount).err();
+			}
+			Message::Gain => self.warning = fuel::gain(character, self.amount).err(),
+			Message::NextTurn => extras.session.fuel.end_turn(),
+		}
+	}
+
+	pub fn view(&self, character: &Character, extras: &CharacterExtras) -> Element<Message> {
+		let Some(name) = character.splat.fuel() else {
+			return column![].into();
+		};
+		let name = name.translated();
+
+		let boxes = SheetDots::new(
+			character.fuel,
+			0,
+			character.max_fuel(),
+			Shape::Boxes,
+			Some(10),
+			Message::FuelChanged,
+		);
+
+		let used = &extras.session.fuel;
+		let limit = fuel::per_turn(character);
+
+		let actions = row![
+			text_input("", &self.amount.to_string())
+				.on_input(|val| Message::Amount(val.parse().unwrap_or(0)))
+				.padding(INPUT_PADDING)
+				.width(Length::Fixed(30.0)),
+			button(text(fl!("spend")))
+				.padding(INPUT_PADDING)
+				.on_press(Message::Spend),
+			button(text(fl!("gain")))
+				.padding(INPUT_PADDING)
+				.on_press(Message::Gain),
+			button(text(fl!("next-turn")))
+				.padding(INPUT_PADDING)
+				.on_press(Message::NextTurn),
+		]
+		.spacing(3)
+		.align_items(Alignment::Center);
+
+		let mut col = column![
+			text(name.clone()).size(H3_SIZE),
+			boxes,
+			actions,
+			text(fl!(
+				"fuel-used",
+				turn = used.turn,
+				limit = limit,
+				scene = used.scene
+			)),
+		]
+		.spacing(TITLE_SPACING)
+		.align_items(Alignment::Center);
+
+		if let Some(warning) = self.warning {
+			let warning = match warning {
+				Error::Limit(limit) => fl!("fuel-limit", fuel = name, limit = limit),
+				Error::Empty => fl!("fuel-empty", fuel = name),
+				Error::Full => fl!("fuel-full", fuel = name),
+			};
+
+			col = col.push(text(warning).style(theme::Text::Color(Color::from_rgb(0.8, 0.0, 0.0))));
+		}
+
+		col.into()
+	}
+}
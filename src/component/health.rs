@@ -0,0 +1,216 @@
+use std::fmt::Write;
+
+use cofd::{character::Wound, prelude::*};
+use iced::{
+	widget::{button, column, pick_list, row, text, text_input},
+	Alignment, Length,
+};
+
+use super::sources;
+use crate::{
+	extras::CharacterExtras,
+	fl, health, i18n,
+	i18n::{Translate, Translated},
+	modifiers::{self, Breakdown, Target},
+	widget::track::HealthTrack,
+	Element, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
+};
+
+const DAMAGE_KINDS: [Wound; 3] = [Wound::Bashing, Wound::Lethal, Wound::Aggravated];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+	Minutes,
+	Hours,
+	Days,
+	Weeks,
+}
+
+impl TimeUnit {
+	pub fn all() -> [TimeUnit; 4] {
+		[Self::Minutes, Self::Hours, Self::Days, Self::Weeks]
+	}
+
+	pub fn minutes(self) -> u32 {
+		match self {
+			Self::Minutes => 1,
+			Self::Hours => 60,
+			Self::Days => 24 * 60,
+			Self::Weeks => 7 * 24 * 60,
+		}
+	}
+}
+
+impl Translate for TimeUnit {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(match self {
+			Self::Minutes => "minutes",
+			Self::Hours => "hours",
+			Self::Days => "days",
+			Self::Weeks => "weeks",
+		})
+	}
+}
+
+fn duration(total: u32) -> String {
+	let days = total / TimeUnit::Days.minutes();
+	let hours = total % TimeUnit::Days.minutes() / TimeUnit::Hours.minutes();
+	let minutes = total % TimeUnit::Hours.minutes();
+
+	fl!("duration", days = days, hours = hours, minutes = minutes)
+}
+
+#[derive(Debug, Clone)]
+pub struct HealthComponent {
+	amount: u16,
+	kind: Wound,
+	time: u32,
+	unit: TimeUnit,
+}
+
+#[derive(Clone, Copy)]
+pub enum Message {
+	HealthChanged(Wound),
+	Amount(u16),
+	Kind(Wound),
+	Take,
+	Heal,
+	FuelHeal,
+	Time(u32),
+	Unit(TimeUnit),
+	Rest,
+}
+
+impl HealthComponent {
+	pub fn new() -> Self {
+		Self {
+			amount: 1,
+			kind: Wound::Bashing,
+			time: 1,
+			unit: TimeUnit::Hours,
+		}
+	}
+
+	pub fn update(
+		&mut self,
+		message: Message,
+		character: &mut Character,
+		extras: &mut CharacterExtras,
+	) {
+		let max = modifiers::max_health(character, extras) as usize;
+
+		match message {
+			Message::HealthChanged(wound) => character.health_mut().poke(&wound),
+			Message::Amount(amount) => self.amount = amount,
+			Message::Kind(kind) => self.kind = kind,
+			Message::Take => {
+				health::wound(character.health_mut(), max, self.kind, self.amount);
+				// A fresh injury interrupts whatever rest was under way.
+				extras.recovery.progress = 0;
+			}
+			Message::Heal => health::heal(character.health_mut(), max, self.kind, self.amount),
+			Message::FuelHeal => {
+				health::fuel_heal(character, &mut extras.session.fuel, max);
+			}
+			Message::Time(time) => self.time = time,
+			Message::Unit(unit) => self.unit = unit,
+			Message::Rest => {
+				if health::heals_naturally(character) {
+					let minutes = self.time.saturating_mul(self.unit.minutes());
+					extras.recovery.rest(character.health_mut(), max, minutes);
+				}
+			}
+		}
+	}
+
+	pub fn view(
+		&self,
+		character: &Character,
+		extras: &CharacterExtras,
+		breakdown: &Breakdown,
+	) -> Element<Message> {
+		let max = modifiers::max_health(character, extras) as usize;
+
+		let track = HealthTrack::new(character.health().clone(), max, Message::HealthChanged);
+
+		let wp = modifiers::wound_penalty(character, extras);
+		let mut label = fl!("health");
+
+		if wp > 0 {
+			write!(label, " (-{wp})").unwrap();
+		}
+
+		let kinds: Vec<Translated<Wound>> = DAMAGE_KINDS.into_iter().map(Into::into).collect();
+		let damage = row![
+			text_input("", &self.amount.to_string())
+				.on_input(|val| Message::Amount(val.parse().unwrap_or(0)))
+				.padding(INPUT_PADDING)
+				.width(Length::Fixed(30.0)),
+			pick_list(kinds, Some::<Translated<Wound>>(self.kind.into()), |val| {
+				Message::Kind(*val)
+			})
+			.padding(INPUT_PADDING),
+			button(text(fl!("take")))
+				.padding(INPUT_PADDING)
+				.on_press(Message::Take),
+			button(text(fl!("heal")))
+				.padding(INPUT_PADDING)
+				.on_press(Message::Heal),
+		]
+		.spacing(3)
+		.align_items(Alignment::Center);
+
+		// A change in Size changes Health along with it.
+		let mut parts = breakdown.parts(character, |character| i32::from(character.max_health()));
+		parts.extend(modifiers::parts(character, extras, Target::Health));
+		parts.extend(modifiers::parts(character, extras, Target::Size));
+
+		let mut col = column![sources(text(label).size(H3_SIZE), &parts), track, damage]
+			.spacing(TITLE_SPACING)
+			.align_items(Alignment::Center);
+
+		if health::heals_naturally(character) {
+			let units: Vec<Translated<TimeUnit>> =
+				TimeUnit::all().into_iter().map(Into::into).collect();
+
+			col = col.push(
+				row![
+					text_input("", &self.time.to_string())
+						.on_input(|val| Message::Time(val.parse().unwrap_or(0)))
+						.padding(INPUT_PADDING)
+						.width(Length::Fixed(30.0)),
+					pick_list(
+						units,
+						Some::<Translated<TimeUnit>>(self.unit.into()),
+						|val| { Message::Unit(*val) }
+					)
+					.padding(INPUT_PADDING),
+					button(text(fl!("advance-time")))
+						.padding(INPUT_PADDING)
+						.on_press(Message::Rest),
+				]
+				.spacing(3)
+				.align_items(Alignment::Center),
+			);
+
+			let damage = character.health();
+			if let Some(next) = extras.recovery.next(damage, max) {
+				col = col.push(text(fl!(
+					"next-heal",
+					time = duration(next),
+					total = duration(extras.recovery.total(damage, max))
+				)));
+			}
+		} else if let Some(fuel) = character.splat.fuel() {
+			let within_limit = health::can_fuel_heal(character, &extras.session.fuel, max);
+
+			col = col.push(
+				button(text(fl!("heal-with", fuel = fuel.translated())))
+					.padding(INPUT_PADDING)
+					.on_press_maybe(within_limit.then_some(Message::FuelHeal)),
+			);
+		}
+
+		col.into()
+	}
+}
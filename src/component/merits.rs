@@ -3,42 +3,90 @@ use cofd::{
 	splat::{Merit, SplatTrait},
 };
 use iced::{
-	widget::{column, pick_list, row, text, text_input, Column, Row},
-	Alignment, Length,
+	theme,
+	widget::{
+		button, checkbox, column, container, pick_list, row, text, text_input, tooltip, Column, Row,
+	},
+	Alignment, Color, Length,
 };
 
 use crate::{
+	catalog::{
+		merits,
+		styles::{self, StyleEntry},
+	},
 	fl, i18n,
-	i18n::Translated,
+	i18n::{Translate, Translated},
 	widget::dots::{Shape, SheetDots},
 	Element, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
 };
 
 #[derive(Debug, Clone)]
-pub struct MeritComponent;
+pub struct MeritComponent {
+	/// Also list the Merits the character doesn't qualify for, with the reasons why.
+	show_all: bool,
+	/// The owned Merit whose rules are shown.
+	selected: Option<usize>,
+	/// Owned Style Merits with their maneuvers listed.
+	expanded: Vec<usize>,
+}
 
 #[derive(Clone)]
-pub struct Message(usize, Merit, u16);
+pub enum Message {
+	Changed(usize, Merit, u16),
+	ShowAll(bool),
+	Select(usize),
+	Expand(usize),
+}
 
 impl MeritComponent {
 	pub fn new() -> Self {
-		Self
+		Self {
+			show_all: false,
+			selected: None,
+			expanded: Vec::new(),
+		}
 	}
 
 	pub fn update(&mut self, message: Message, character: &mut Character) {
-		let Message(i, ability, val) = message;
+		let (i, ability, val) = match message {
+			Message::Changed(i, ability, val) => (i, ability, val),
+			Message::ShowAll(show_all) => {
+				self.show_all = show_all;
+				return;
+			}
+			Message::Select(i) => {
+				self.selected = if self.selected == Some(i) {
+					None
+				} else {
+					Some(i)
+				};
+				return;
+			}
+			Message::Expand(i) => {
+				if self.expanded.contains(&i) {
+					self.expanded.retain(|e| *e != i);
+				} else {
+					self.expanded.push(i);
+				}
+				return;
+			}
+		};
+
 		if let Merit::_Custom(str) = &ability {
 			if str.contains("---") {
 				return;
 			}
 		}
 
+		let val = merits::snap(&ability, val);
 		let mut flag = false;
 
 		if character.merits.len() == i {
 			if !ability.get_modifiers(val).is_empty() {
 				flag = true;
 			}
+			self.selected = Some(i);
 			character.merits.push((ability, val));
 		} else {
 			let old = character.merits.remove(i);
@@ -46,7 +94,9 @@ impl MeritComponent {
 				flag = true;
 			}
 
-			if !ability.name().is_empty() {
+			if ability.name().is_empty() {
+				self.selected = None;
+			} else {
 				character.merits.insert(i, (ability, val));
 			}
 		}
@@ -56,6 +106,7 @@ impl MeritComponent {
 		}
 	}
 
+	#[allow(clippy::too_many_lines)]
 	pub fn view(&self, character: &Character) -> Element<Message> {
 		let mut vec = Vec::new();
 		vec.push(Merit::_Custom(String::from("--- Mental Merits ---")));
@@ -78,24 +129,28 @@ impl MeritComponent {
 		let attributes = &character.attributes();
 		let skills = &character.skills();
 
-		let vec: Vec<Translated<Merit>> = vec
-			.iter()
-			.filter(|&e| {
+		let (vec, unavailable): (Vec<Merit>, Vec<Merit>) = vec
+			.into_iter()
+			.filter(|e| {
 				character
 					.merits
 					.iter()
 					.filter(|(merit, _)| *merit == *e)
-					.count() == 0 && e.is_available(character, attributes, skills)
+					.count() == 0
 			})
-			.cloned()
-			.map(Into::into)
-			.collect();
+			.partition(|e| {
+				matches!(e, Merit::_Custom(_))
+					|| (e.is_available(character, attributes, skills)
+						&& merits::unmet(e, character).is_empty())
+			});
+
+		let vec: Vec<Translated<Merit>> = vec.into_iter().map(Into::into).collect();
 
 		let mut col = Column::new().spacing(3);
 		for (i, (merit, val)) in character.merits.iter().cloned().enumerate() {
 			let item: Element<Message> = if let Merit::_Custom(str) = &merit {
 				text_input("", str)
-					.on_input(move |key| Message(i, Merit::_Custom(key), val))
+					.on_input(move |key| Message::Changed(i, Merit::_Custom(key), val))
 					.width(Length::Fill)
 					.padding(INPUT_PADDING)
 					.into()
@@ -103,7 +158,7 @@ impl MeritComponent {
 				pick_list(
 					vec.clone(),
 					Some::<Translated<Merit>>(merit.clone().into()),
-					move |key| Message(i, key.unwrap(), val),
+					move |key| Message::Changed(i, key.unwrap(), val),
 				)
 				.padding(INPUT_PADDING)
 				.text_size(20)
@@ -111,27 +166,267 @@ impl MeritComponent {
 				.into()
 			};
 
-			let dots = SheetDots::new(val, 0, 5, Shape::Dots, None, {
+			let max = merits::ratings(&merit).last().copied().unwrap_or(5);
+			let dots = SheetDots::new(val, 0, max, Shape::Dots, None, {
 				let merit = merit.clone();
-				move |val| Message(i, merit.clone(), val)
+				move |val| Message::Changed(i, merit.clone(), val)
 			});
 
-			col = col.push(row![item, dots]);
+			let mut row = row![item];
+			if let Some(rules) = rules(merit.name(), &merits::prerequisites(&merit)) {
+				row = row.push(tooltip(
+					button(text("?"))
+						.padding(INPUT_PADDING)
+						.on_press(Message::Select(i)),
+					container(rules).style(theme::Container::Box),
+					tooltip::Position::Bottom,
+				));
+			}
+
+			col = col.push(row.push(dots).spacing(3).align_items(Alignment::Center));
+
+			if let Some(parameters) = parameters(i, &merit, val) {
+				col = col.push(parameters);
+			}
+
+			if let Some(style) = styles::get(&merit) {
+				let expanded = self.expanded.contains(&i);
+				col = col.push(
+					button(text(format!(
+						"{} {}",
+						if expanded { "▾" } else { "▸" },
+						fl!("maneuvers")
+					)))
+					.padding(INPUT_PADDING)
+					.style(theme::Button::Text)
+					.on_press(Message::Expand(i)),
+				);
+
+				if expanded {
+					col = col.push(maneuvers(style, val));
+				}
+			}
+
+			// Lowering a trait can leave a Merit the character already has without its prerequisites.
+			let unmet = merits::unmet(&merit, character);
+			if !unmet.is_empty() {
+				col = col.push(
+					text(fl!("prerequisites-lost", prerequisites = unmet.join(", ")))
+						.style(theme::Text::Color(Color::from_rgb(0.8, 0.0, 0.0))),
+				);
+			}
 		}
 
 		col = col.push(
 			pick_list(vec, None::<Translated<Merit>>, {
 				let len = character.merits.len();
-				move |key| Message(len, key.unwrap(), 0)
+				move |key| Message::Changed(len, key.unwrap(), 0)
 			})
 			.padding(INPUT_PADDING)
 			.text_size(20)
 			.width(Length::Fill),
 		);
 
-		column![text(fl!("merits")).size(H3_SIZE), col]
+		col = col.push(checkbox(fl!("show-all-merits"), self.show_all).on_toggle(Message::ShowAll));
+
+		if self.show_all {
+			for merit in unavailable {
+				let unmet = merits::unmet(&merit, character);
+
+				col = col.push(
+					text(fl!(
+						"merit-requires",
+						merit = merit.translated(),
+						prerequisites = unmet.join(", ")
+					))
+					.style(theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+				);
+			}
+		}
+
+		let mut col = column![text(fl!("merits")).size(H3_SIZE), col]
 			.spacing(TITLE_SPACING)
-			.align_items(Alignment::Center)
+			.align_items(Alignment::Center);
+
+		if let Some((merit, _)) = self.selected.and_then(|i| character.merits.get(i))
+			&& let Some(rules) = rules(merit.name(), &merits::prerequisites(merit))
+		{
+			col = col.push(
+				container(column![text(merit.translated()).size(H3_SIZE), rules].spacing(3))
+					.style(theme::Container::Box)
+					.padding(5)
+					.width(Length::Fill),
+			);
+		}
+
+		col.into()
+	}
+}
+
+/// Description, prerequisites, what each dot gives and where the rules come from, read from the
+/// Fluent attributes of `key`. Nothing when there's no description.
+pub fn rules<'a, Message: 'a>(key: &str, prerequisites: &[String]) -> Option<Element<'a, Message>> {
+	let mut col = Column::new()
+		.spacing(3)
+		.max_width(400)
+		.push(text(i18n::attr(key, "description")?));
+
+	if !prerequisites.is_empty() {
+		col = col.push(text(fl!(
+			"merit-prerequisites",
+			prerequisites = prerequisites.join(", ")
+		)));
+	}
+
+	for dot in 1..=5 {
+		if let Some(effect) = i18n::attr(key, &format!("dot-{dot}")) {
+			col = col.push(text(format!("{} {effect}", "•".repeat(dot))));
+		}
+	}
+
+	if let Some(source) = i18n::attr(key, "source") {
+		col = col.push(
+			text(fl!("source", source = source))
+				.style(theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+		);
+	}
+
+	Some(col.into())
+}
+
+/// Every maneuver of the Style, with the ones the rating doesn't reach yet greyed out.
+fn maneuvers<'a>(style: &StyleEntry, val: u16) -> Element<'a, Message> {
+	let mut col = Column::new().spacing(3).padding([0, 0, 0, 15]);
+
+	for maneuver in &style.maneuvers {
+		let label = format!(
+			"{} {}",
+			"•".repeat(maneuver.dots.into()),
+			maneuver.translated()
+		);
+
+		let item: Element<Message> = if maneuver.dots <= val {
+			text(label).into()
+		} else {
+			text(format!(
+				"{label} ({})",
+				fl!("maneuver-locked", dots = maneuver.dots)
+			))
+			.style(theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5)))
 			.into()
+		};
+
+		col = col.push(tooltip(
+			item,
+			container(text(maneuver.description()))
+				.style(theme::Container::Box)
+				.max_width(400),
+			tooltip::Position::Bottom,
+		));
 	}
+
+	col.width(Length::Fill).into()
+}
+
+fn skills(skills: &[Skill]) -> Vec<Translated<Skill>> {
+	skills.iter().copied().map(Into::into).collect()
+}
+
+/// Editors for the details some Merits carry, like the group of a Status or a Professional
+/// Training's Asset Skills.
+fn parameters<'a>(i: usize, merit: &Merit, val: u16) -> Option<Element<'a, Message>> {
+	let (placeholder, str, f): (String, &String, fn(String) -> Merit) = match merit {
+		Merit::Status(str) => (fl!("status-group"), str, Merit::Status),
+		Merit::Language(str) => (fl!("language-name"), str, Merit::Language),
+		Merit::SafePlace(str) => (fl!("safe-place-location"), str, Merit::SafePlace),
+		Merit::Contacts(str) => (fl!("contacts-spheres"), str, Merit::Contacts),
+		Merit::ProfessionalTraining {
+			profession,
+			skills: asset_skills,
+			skill,
+		} => {
+			let all = Skill::all();
+			let [first, second] = *asset_skills;
+			let profession = profession.clone();
+			let free_dot = *skill;
+
+			let with = move |profession: String, asset_skills: [Skill; 2], skill: Option<Skill>| {
+				Message::Changed(
+					i,
+					Merit::ProfessionalTraining {
+						profession,
+						skills: asset_skills,
+						skill,
+					},
+					val,
+				)
+			};
+
+			let mut row = row![
+				text_input(&fl!("profession"), &profession)
+					.on_input(move |val| with(val, [first, second], free_dot))
+					.padding(INPUT_PADDING),
+				pick_list(skills(&all), Some::<Translated<Skill>>(first.into()), {
+					let profession = profession.clone();
+					move |val| with(profession.clone(), [*val, second], free_dot)
+				})
+				.padding(INPUT_PADDING),
+				pick_list(skills(&all), Some::<Translated<Skill>>(second.into()), {
+					let profession = profession.clone();
+					move |val| with(profession.clone(), [first, *val], free_dot)
+				})
+				.padding(INPUT_PADDING),
+			]
+			.spacing(3);
+
+			// On the Job Training, at four dots, gives a free dot in a Skill of the player's choice;
+			// it isn't an Asset Skill and doesn't need to be one.
+			if val >= 4 {
+				row = row.push(
+					pick_list(
+						skills(&all),
+						free_dot.map(Into::<Translated<Skill>>::into),
+						move |val| with(profession.clone(), [first, second], Some(*val)),
+					)
+					.placeholder(fl!("training-skill"))
+					.padding(INPUT_PADDING),
+				);
+			}
+
+			return Some(row.into());
+		}
+		Merit::DefensiveCombat(active, skill) => {
+			let skill = *skill;
+			let active = *active;
+
+			return Some(
+				row![
+					checkbox(fl!("use-for-defense"), active).on_toggle(move |active| {
+						Message::Changed(i, Merit::DefensiveCombat(active, skill), val)
+					}),
+					pick_list(
+						skills(&[Skill::Brawl, Skill::Weaponry]),
+						skill.map(Into::<Translated<Skill>>::into),
+						move |skill| Message::Changed(
+							i,
+							Merit::DefensiveCombat(active, Some(*skill)),
+							val
+						)
+					)
+					.padding(INPUT_PADDING),
+				]
+				.spacing(3)
+				.align_items(Alignment::Center)
+				.into(),
+			);
+		}
+		_ => return None,
+	};
+
+	Some(
+		text_input(&placeholder, str)
+			.on_input(move |str| Message::Changed(i, f(str), val))
+			.padding(INPUT_PADDING)
+			.into(),
+	)
 }
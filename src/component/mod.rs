@@ -1,16 +1,30 @@
+pub mod aspirations;
 pub mod attributes;
+pub mod conditions;
 pub mod forms;
+pub mod fuel;
+pub mod health;
 pub mod info;
 pub mod integrity;
 mod list;
 pub mod merits;
 pub mod skills;
+mod sources;
+pub mod tilts;
 pub mod traits;
+pub mod willpower;
 
+pub use aspirations::AspirationsComponent;
 pub use attributes::AttributeBar;
+pub use conditions::ConditionsComponent;
 pub use forms::FormsComponent;
+pub use fuel::FuelComponent;
+pub use health::HealthComponent;
 pub use integrity::IntegrityComponent;
 pub use list::list;
 pub use merits::MeritComponent;
 pub use skills::SkillsComponent;
+pub use sources::sources;
+pub use tilts::TiltsComponent;
 pub use traits::TraitsComponent;
+pub use willpower::WillpowerComponent;
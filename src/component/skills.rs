@@ -8,40 +8,59 @@ use cofd::{
 };
 use iced::{
 	theme::{self},
-	widget::{button, checkbox, column, row, text, text_input, Column, Row},
+	widget::{button, checkbox, column, container, pick_list, row, text, text_input, Column, Row},
 	Alignment, Color, Element, Length,
 };
 
-use super::list;
+use super::sources;
 use crate::{
+	caps::{self, Kind},
+	extras::CharacterExtras,
 	fl,
-	i18n::Translate,
-	view::overview::vec_changed,
+	i18n::{Translate, Translated},
+	modifiers::Breakdown,
+	specialties::{self, Source, Specialty},
+	training,
 	widget::dots::{Shape, SheetDots},
-	H2_SIZE, H3_SIZE, TITLE_SPACING,
+	H2_SIZE, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
 };
 
+/// The Specialty being added in the panel below the Skills.
 #[derive(Debug, Clone)]
 pub struct SkillsComponent {
-	specialty_skill: Option<Skill>,
+	skill: Skill,
+	name: String,
+	source: Source,
+	error: Option<specialties::Error>,
 }
 
 #[derive(Clone)]
 pub enum Message {
 	Skill(u16, Skill),
 	RoteSkill(Skill),
-	Specialty(Skill, usize, String),
 	SpecialtySkill(Skill),
+	SpecialtyName(String),
+	SpecialtySource(Source),
+	AddSpecialty,
+	RemoveSpecialty(Specialty),
 }
 
 impl SkillsComponent {
 	pub fn new() -> Self {
 		Self {
-			specialty_skill: None,
+			skill: Skill::Academics,
+			name: String::new(),
+			source: Source::default(),
+			error: None,
 		}
 	}
 
-	pub fn update(&mut self, message: Message, character: &mut Character) {
+	pub fn update(
+		&mut self,
+		message: Message,
+		character: &mut Character,
+		extras: &mut CharacterExtras,
+	) {
 		match message {
 			Message::Skill(val, skill) => *character.base_skills_mut().get_mut(skill) = val,
 			Message::RoteSkill(skill) => {
@@ -60,35 +79,45 @@ impl SkillsComponent {
 					}
 				}
 			}
-			Message::Specialty(skill, i, val) => {
-				if let Some(vec) = character.specialties.get_mut(&skill) {
-					if val.is_empty() {
-						vec.remove(i);
-					} else {
-						vec_changed(i, val, vec);
+			Message::SpecialtySkill(skill) => {
+				self.skill = skill;
+				self.error = None;
+			}
+			Message::SpecialtyName(name) => {
+				self.name = name;
+				self.error = None;
+			}
+			Message::SpecialtySource(source) => {
+				self.source = source;
+				self.error = None;
+			}
+			Message::AddSpecialty => {
+				match specialties::add(character, extras, self.skill, &self.name, self.source) {
+					Ok(()) => {
+						self.name.clear();
+						self.error = None;
 					}
-				} else {
-					character.specialties.insert(skill, vec![val]);
+					Err(err) => self.error = Some(err),
 				}
 			}
-			Message::SpecialtySkill(skill) => {
-				if let Some(cur) = self.specialty_skill
-					&& cur == skill
-				{
-					self.specialty_skill = None;
-				} else {
-					self.specialty_skill = Some(skill);
-				}
+			Message::RemoveSpecialty(specialty) => {
+				specialties::remove(character, extras, &specialty);
 			}
 		}
 	}
 
-	pub fn view(&self, character: &Character) -> Element<Message> {
+	pub fn view(
+		&self,
+		character: &Character,
+		extras: &CharacterExtras,
+		breakdown: &Breakdown,
+	) -> Element<Message> {
 		column![
 			text(fl!("skills").to_uppercase()).size(H2_SIZE),
-			self.mk_skill_col(character, TraitCategory::Mental),
-			self.mk_skill_col(character, TraitCategory::Physical),
-			self.mk_skill_col(character, TraitCategory::Social),
+			self.mk_skill_col(character, extras, breakdown, TraitCategory::Mental),
+			self.mk_skill_col(character, extras, breakdown, TraitCategory::Physical),
+			self.mk_skill_col(character, extras, breakdown, TraitCategory::Social),
+			self.specialties(character, extras),
 		]
 		.spacing(10)
 		// .padding(15)
@@ -97,8 +126,16 @@ impl SkillsComponent {
 		.into()
 	}
 
-	fn mk_skill_col(&self, character: &Character, category: TraitCategory) -> Element<Message> {
+	#[allow(clippy::too_many_lines)]
+	fn mk_skill_col(
+		&self,
+		character: &Character,
+		extras: &CharacterExtras,
+		breakdown: &Breakdown,
+		category: TraitCategory,
+	) -> Element<Message> {
 		let mut col = Column::new().spacing(3);
+		let all = specialties::all(character, extras);
 
 		for skill in Skill::get_by_category(category) {
 			let mut row = Row::new().spacing(5).width(Length::Fill);
@@ -117,57 +154,55 @@ impl SkillsComponent {
 				);
 			}
 
-			let specialties = character
-				.specialties
-				.get(&skill)
-				.cloned()
-				.unwrap_or_default();
+			// Professional Training's Asset Skills are starred.
+			let label = if training::is_asset(character, skill) {
+				format!("{} ★", skill.translated())
+			} else {
+				skill.translated()
+			};
+
+			let mut parts = breakdown.parts(character, |character| {
+				i32::from(character._modified(ModifierTarget::BaseSkill(skill)))
+			});
+			let dot = training::skill_dot(character, skill);
+			if dot > 0 {
+				parts.push((fl!("professional-training"), i32::from(dot)));
+			}
 
-			row = row.push(
-				button(text(skill.translated()).style(if specialties.is_empty() {
+			let v = character.base_skills().get(skill);
+			let button = button(
+				text(label).style(if caps::exceeds(character, Kind::Skill, v) {
+					theme::Text::Color(Color::from_rgb(0.8, 0.0, 0.0))
+				} else if !all
+					.iter()
+					.any(|specialty| specialty.skill == skill && specialty.active)
+				{
 					theme::Text::Default
 				} else {
 					theme::Text::Color(Color::from_rgb(0.0, 0.7, 0.0))
-				}))
-				.width(Length::Fill)
-				.padding(0)
-				.style(theme::Button::Text)
-				.on_press(Message::SpecialtySkill(skill)),
-			);
+				}),
+			)
+			.width(Length::Fill)
+			.padding(0)
+			.style(theme::Button::Text)
+			.on_press(Message::SpecialtySkill(skill));
 
-			let v = character.base_skills().get(skill);
-			let val = character._modified(ModifierTarget::BaseSkill(skill));
+			row = row.push(container(sources(button, &parts)).width(Length::Fill));
+
+			let val = character._modified(ModifierTarget::BaseSkill(skill))
+				+ training::skill_dot(character, skill);
 			let mod_ = val - v;
 
 			row = row.push(SheetDots::new(
 				val,
 				mod_,
-				5,
+				caps::of(character, Kind::Skill) + mod_,
 				Shape::Dots,
-				None,
+				Some(caps::ROW),
 				move |val| Message::Skill(val - mod_, skill),
 			));
 
-			if let Some(specialty_skill) = self.specialty_skill {
-				if skill.eq(&specialty_skill) {
-					col = col.push(Column::new().push(row).push(list(
-						String::new(),
-						Some(specialties.len() + 1),
-						None,
-						specialties,
-						move |i, val| {
-							text_input("", &val.unwrap_or_default())
-								.on_input(move |val| Message::Specialty(skill, i, val))
-								.padding(0)
-								.into()
-						},
-					)));
-				} else {
-					col = col.push(row);
-				}
-			} else {
-				col = col.push(row);
-			}
+			col = col.push(row);
 		}
 
 		column![
@@ -179,4 +214,106 @@ impl SkillsComponent {
 		.align_items(Alignment::Center)
 		.into()
 	}
+
+	/// Every Specialty with its Skill and source, and a form to add more.
+	fn specialties(&self, character: &Character, extras: &CharacterExtras) -> Element<Message> {
+		let all = specialties::all(character, extras);
+		let mut col = Column::new()
+			.spacing(3)
+			.align_items(Alignment::Center)
+			.push(text(fl!("specialties")).size(H3_SIZE));
+
+		for specialty in &all {
+			let name = if specialties::is_duplicate(&all, specialty) {
+				text(format!(
+					"{} ({})",
+					specialty.name,
+					fl!("duplicate-specialty")
+				))
+				.style(theme::Text::Color(Color::from_rgb(0.8, 0.0, 0.0)))
+			} else {
+				text(&specialty.name)
+			};
+
+			// A free Specialty Professional Training no longer grants stays listed so it can be
+			// removed.
+			let source = if specialty.active {
+				specialty.source.translated()
+			} else {
+				format!(
+					"{} ({})",
+					specialty.source.translated(),
+					fl!("specialty-inactive")
+				)
+			};
+
+			col = col.push(
+				row![
+					text(specialty.skill.translated()).width(Length::FillPortion(2)),
+					name.width(Length::FillPortion(3)),
+					text(source)
+						.size(13)
+						.style(theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5)))
+						.width(Length::FillPortion(2)),
+					button(text("x"))
+						.padding(INPUT_PADDING)
+						.on_press(Message::RemoveSpecialty(specialty.clone())),
+				]
+				.spacing(5)
+				.align_items(Alignment::Center),
+			);
+		}
+
+		let skills: Vec<Translated<Skill>> = Skill::all().into_iter().map(Into::into).collect();
+		let suggestions: Vec<String> = specialties::suggestions(self.skill)
+			.into_iter()
+			.filter(|suggestion| {
+				!all.iter().any(|specialty| {
+					specialty.skill == self.skill && specialties::same(&specialty.name, suggestion)
+				})
+			})
+			.collect();
+		let sources: Vec<Translated<Source>> = Source::all().into_iter().map(Into::into).collect();
+
+		col = col.push(
+			row![
+				pick_list(
+					skills,
+					Some::<Translated<Skill>>(self.skill.into()),
+					|val| { Message::SpecialtySkill(*val) }
+				)
+				.padding(INPUT_PADDING),
+				text_input(&fl!("specialty"), &self.name)
+					.on_input(Message::SpecialtyName)
+					.on_submit(Message::AddSpecialty)
+					.padding(INPUT_PADDING),
+				pick_list(suggestions, None::<String>, Message::SpecialtyName)
+					.placeholder(fl!("suggestions"))
+					.padding(INPUT_PADDING),
+				pick_list(
+					sources,
+					Some::<Translated<Source>>(self.source.into()),
+					|val| Message::SpecialtySource(*val)
+				)
+				.padding(INPUT_PADDING),
+				button(text(fl!("add-specialty")))
+					.padding(INPUT_PADDING)
+					.on_press(Message::AddSpecialty),
+			]
+			.spacing(5)
+			.align_items(Alignment::Center),
+		);
+
+		if let Some(error) = self.error {
+			let error = match error {
+				specialties::Error::Empty => fl!("specialty-empty"),
+				specialties::Error::Duplicate => fl!("duplicate-specialty"),
+				specialties::Error::Training => fl!("specialty-training"),
+			};
+
+			col = col.push(text(error).style(theme::Text::Color(Color::from_rgb(0.8, 0.0, 0.0))));
+		}
+
+		col.into()
+	}
 }
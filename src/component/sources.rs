@@ -0,0 +1,28 @@
+use iced::{
+	theme,
+	widget::{container, text, tooltip, Column},
+};
+
+use crate::Element;
+
+/// Shows what makes up a trait's value when hovering `content`, one line per source.
+pub fn sources<'a, Message: 'a>(
+	content: impl Into<Element<'a, Message>>,
+	parts: &[(String, i32)],
+) -> Element<'a, Message> {
+	if parts.is_empty() {
+		return content.into();
+	}
+
+	let mut col = Column::new().spacing(3);
+	for (source, value) in parts {
+		col = col.push(text(format!("{source}: {value:+}")));
+	}
+
+	tooltip(
+		content,
+		container(col).style(theme::Container::Box).padding(5),
+		tooltip::Position::Bottom,
+	)
+	.into()
+}
@@ -0,0 +1,133 @@
+use std::fmt::{self, Display};
+
+use iced::{
+	theme,
+	widget::{button, container, pick_list, row, text, text_input, tooltip, Column},
+	Alignment, Color, Length,
+};
+
+use crate::{
+	catalog::{tilts, TILTS},
+	fl,
+	i18n::Translate,
+	tilts::{self as tilt, Tilt},
+	Element, INPUT_PADDING,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TiltPick(String, String);
+
+impl Display for TiltPick {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.1)
+	}
+}
+
+/// Active Tilts with their remaining turns. Used on the sheet and for every encounter participant.
+#[derive(Debug, Clone)]
+pub struct TiltsComponent {
+	turns: u16,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+	Add(TiltPick),
+	Turns(u16),
+	Remove(usize),
+	/// A turn has passed.
+	Tick,
+}
+
+impl TiltsComponent {
+	pub fn new() -> Self {
+		Self { turns: 1 }
+	}
+
+	pub fn update(&mut self, message: Message, tilts: &mut Vec<Tilt>) {
+		match message {
+			Message::Add(TiltPick(key, _)) => {
+				if !tilts.iter().any(|tilt| tilt.key == key) {
+					tilts.push(Tilt::new(key, self.turns));
+				}
+			}
+			Message::Turns(turns) => self.turns = turns,
+			Message::Remove(i) => {
+				if i < tilts.len() {
+					tilts.remove(i);
+				}
+			}
+			Message::Tick => tilt::tick(tilts),
+		}
+	}
+
+	pub fn view(&self, tilts: &[Tilt]) -> Element<Message> {
+		let mut col = Column::new().spacing(3);
+
+		for (i, tilt) in tilts.iter().enumerate() {
+			let mut label = tilt.name();
+			if let Some(turns) = tilt.turns {
+				label = fl!("tilt-turns", name = label, turns = turns);
+			}
+
+			let entry = tilts::get(&tilt.key);
+			let description = entry.map(tilts::TiltEntry::description).unwrap_or_default();
+			let effects = entry
+				.map(|entry| {
+					entry
+						.effects
+						.iter()
+						.map(Translate::translated)
+						.collect::<Vec<_>>()
+						.join(", ")
+				})
+				.unwrap_or_default();
+
+			col = col.push(
+				row![
+					container(tooltip(
+						text(label),
+						container(text(description)).style(theme::Container::Box),
+						tooltip::Position::Bottom,
+					))
+					.width(Length::Fill),
+					text(effects).style(theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+					button(text(fl!("remove")))
+						.padding(INPUT_PADDING)
+						.on_press(Message::Remove(i)),
+				]
+				.spacing(5)
+				.align_items(Alignment::Center),
+			);
+		}
+
+		let catalog: Vec<TiltPick> = TILTS
+			.iter()
+			.filter(|entry| !tilts.iter().any(|tilt| tilt.key == entry.key))
+			.map(|entry| TiltPick(entry.key.clone(), entry.translated()))
+			.collect();
+
+		let mut add = row![
+			pick_list(catalog, None::<TiltPick>, Message::Add)
+				.placeholder(fl!("add-tilt"))
+				.padding(INPUT_PADDING)
+				.width(Length::Fill),
+			text(fl!("turns")),
+			text_input("", &self.turns.to_string())
+				.on_input(|val| Message::Turns(val.parse().unwrap_or(0)))
+				.padding(INPUT_PADDING)
+				.width(Length::Fixed(40.0)),
+		]
+		.spacing(5)
+		.align_items(Alignment::Center);
+
+		if tilts.iter().any(|tilt| tilt.turns.is_some()) {
+			add = add.push(
+				button(text(fl!("next-turn")))
+					.padding(INPUT_PADDING)
+					.on_press(Message::Tick),
+			);
+		}
+
+		col.push(add).into()
+	}
+}
@@ -1,25 +1,84 @@
-use cofd::prelude::*;
-use cofd::traits::DerivedTrait;
+use cofd::{prelude::*, traits::DerivedTrait};
 use iced::{
-	widget::{column, row, text, text_input},
-	Length,
+	widget::{button, column, pick_list, row, text, text_input, Column},
+	Alignment, Length,
 };
 
-use crate::{fl, i18n, Element, INPUT_PADDING};
+use super::sources;
+use crate::{
+	extras::CharacterExtras,
+	fl,
+	i18n::{self, Translate, Translated},
+	modifiers::{self, Adjustment, Breakdown, Target},
+	Element, INPUT_PADDING,
+};
 
 #[derive(Debug, Clone)]
-pub struct TraitsComponent;
+pub struct TraitsComponent {
+	target: Target,
+	name: String,
+	value: String,
+}
 
 #[derive(Clone)]
-pub struct Message(u16, Trait);
+pub enum Message {
+	Trait(u16, Trait),
+	AdjustTarget(Target),
+	AdjustName(String),
+	AdjustValue(String),
+	Adjust,
+	Clear(usize),
+}
 
 impl TraitsComponent {
 	pub fn new() -> Self {
-		Self
+		Self {
+			target: Target::Size,
+			name: String::new(),
+			value: String::new(),
+		}
 	}
 
-	pub fn update(&mut self, message: Message, character: &mut Character) {
-		let Message(val, _trait) = message;
+	pub fn update(
+		&mut self,
+		message: Message,
+		character: &mut Character,
+		extras: &mut CharacterExtras,
+	) {
+		let (val, _trait) = match message {
+			Message::Trait(val, _trait) => (val, _trait),
+			Message::AdjustTarget(target) => {
+				self.target = target;
+				return;
+			}
+			Message::AdjustName(name) => {
+				self.name = name;
+				return;
+			}
+			Message::AdjustValue(value) => {
+				self.value = value;
+				return;
+			}
+			Message::Adjust => {
+				if let Ok(value) = self.value.trim().parse()
+					&& value != 0
+				{
+					extras.adjustments.push(Adjustment {
+						name: std::mem::take(&mut self.name).trim().to_string(),
+						target: self.target,
+						value,
+					});
+					self.value.clear();
+				}
+				return;
+			}
+			Message::Clear(i) => {
+				if i < extras.adjustments.len() {
+					extras.adjustments.remove(i);
+				}
+				return;
+			}
+		};
 
 		match _trait {
 			Trait::DerivedTrait(DerivedTrait::Size) => {
@@ -37,11 +96,17 @@ impl TraitsComponent {
 		}
 	}
 
-	pub fn view(&self, character: &Character) -> Element<Message> {
+	#[allow(clippy::too_many_lines)]
+	pub fn view(
+		&self,
+		character: &Character,
+		extras: &CharacterExtras,
+		breakdown: &Breakdown,
+	) -> Element<Message> {
 		let beats = row![
 			text(format!("{}:", fl!("beats"))),
 			text_input("", &format!("{}", character.beats))
-				.on_input(|val| { Message(val.parse().unwrap_or(0), Trait::Beats) })
+				.on_input(|val| { Message::Trait(val.parse().unwrap_or(0), Trait::Beats) })
 				.padding(INPUT_PADDING)
 		];
 
@@ -56,7 +121,9 @@ impl TraitsComponent {
 			row![
 				text(format!("{name}:")),
 				text_input("", &format!("{}", character.alternate_beats))
-					.on_input(|val| { Message(val.parse().unwrap_or(0), Trait::AlternateBeats) })
+					.on_input(|val| {
+						Message::Trait(val.parse().unwrap_or(0), Trait::AlternateBeats)
+					})
 					.padding(INPUT_PADDING)
 			]
 		};
@@ -75,23 +142,69 @@ impl TraitsComponent {
 			))]
 		};
 
-		let armor = character.armor();
+		let derived = |target: Target, f: fn(&Character) -> u16| {
+			let mut parts = breakdown.parts(character, |character| i32::from(f(character)));
+			parts.extend(modifiers::parts(character, extras, target));
+			parts
+		};
+
+		// Both kinds of armor share a line, so their sources are told apart.
+		let mut armor = Vec::new();
+		for (target, parts) in [
+			(
+				Target::GeneralArmor,
+				derived(Target::GeneralArmor, |character| character.armor().general),
+			),
+			(
+				Target::BallisticArmor,
+				derived(Target::BallisticArmor, |character| {
+					character.armor().ballistic
+				}),
+			),
+		] {
+			armor.extend(
+				parts
+					.into_iter()
+					.map(|(source, value)| (format!("{source} ({})", target.translated()), value)),
+			);
+		}
+
+		let (general, ballistic) = modifiers::armor(character, extras);
 		column![
 			row![
-				text(format!("{}: {}", fl!("size"), character.size())),
+				sources(
+					text(format!(
+						"{}: {}",
+						fl!("size"),
+						modifiers::size(character, extras)
+					)),
+					&derived(Target::Size, Character::size)
+				),
 				// text_input("", &format!("{}", self.traits.size), |val| {
 				// 	Event(val, Trait::Size)
 				// })
 			],
-			row![text(format!("{}: {}", fl!("speed"), character.speed())),],
-			row![text(format!("{}: {}", fl!("defense"), character.defense())),],
-			row![
+			row![sources(
+				text(format!(
+					"{}: {}",
+					fl!("speed"),
+					modifiers::speed(character, extras)
+				)),
+				&derived(Target::Speed, Character::speed)
+			)],
+			row![sources(
 				text(format!(
-					"{}: {}/{}",
-					fl!("armor"),
-					armor.general,
-					armor.ballistic
+					"{}: {}",
+					fl!("defense"),
+					modifiers::defense(character, extras)
 				)),
+				&derived(Target::Defense, Character::defense)
+			)],
+			row![
+				sources(
+					text(format!("{}: {general}/{ballistic}", fl!("armor"))),
+					&armor
+				),
 				// text_input("", &format!("{}", self.traits.beats), |val| {
 				// 	// if let Some(val) = val.parse() {
 				// 	Event(val, Trait::Armor(Armor::General))
@@ -104,11 +217,14 @@ impl TraitsComponent {
 				// 	// }
 				// })
 			],
-			row![text(format!(
-				"{}: {}",
-				fl!("initiative"),
-				character.initiative()
-			)),],
+			row![sources(
+				text(format!(
+					"{}: {}",
+					fl!("initiative"),
+					modifiers::initiative(character, extras)
+				)),
+				&derived(Target::Initiative, Character::initiative)
+			)],
 			beats,
 			row![text(format!(
 				"{}: {}",
@@ -116,10 +232,70 @@ impl TraitsComponent {
 				character.experience()
 			)),],
 			alternate_beats,
-			alternate_xp
+			alternate_xp,
+			self.adjustments(extras)
 		]
 		// .padding(0)
 		.width(Length::Fill)
 		.into()
 	}
+
+	/// The manual adjustments made so far, and a form to add another.
+	fn adjustments(&self, extras: &CharacterExtras) -> Element<Message> {
+		let mut col = Column::new()
+			.spacing(5)
+			.push(text(format!("{}:", fl!("adjustments"))));
+
+		for (i, adjustment) in extras.adjustments.iter().enumerate() {
+			let name = if adjustment.name.is_empty() {
+				fl!("adjustment")
+			} else {
+				adjustment.name.clone()
+			};
+
+			col = col.push(
+				row![
+					text(format!(
+						"{}: {:+} ({name})",
+						adjustment.target.translated(),
+						adjustment.value
+					))
+					.width(Length::Fill),
+					button(text("x"))
+						.padding(INPUT_PADDING)
+						.on_press(Message::Clear(i)),
+				]
+				.spacing(5)
+				.align_items(Alignment::Center),
+			);
+		}
+
+		let targets: Vec<Translated<Target>> =
+			Target::adjustable().into_iter().map(Into::into).collect();
+
+		col.push(
+			row![
+				pick_list(
+					targets,
+					Some::<Translated<Target>>(self.target.into()),
+					|target| Message::AdjustTarget(*target)
+				)
+				.padding(INPUT_PADDING),
+				text_input(&fl!("adjustment-name"), &self.name)
+					.on_input(Message::AdjustName)
+					.padding(INPUT_PADDING),
+				text_input("+1", &self.value)
+					.on_input(Message::AdjustValue)
+					.on_submit(Message::Adjust)
+					.padding(INPUT_PADDING)
+					.width(Length::Fixed(50.0)),
+				button(text(fl!("add-adjustment")))
+					.padding(INPUT_PADDING)
+					.on_press(Message::Adjust),
+			]
+			.spacing(5)
+			.align_items(Alignment::Center),
+		)
+		.into()
+	}
 }
@@ -0,0 +1,116 @@
+use cofd::prelude::*;
+use iced::{
+	widget::{button, column, row, text},
+	Alignment,
+};
+
+use super::sources;
+use crate::{
+	extras::CharacterExtras,
+	fl,
+	i18n::Translate,
+	modifiers::{self, Breakdown, Target},
+	widget::dots::{Shape, SheetDots},
+	willpower::{self, Spend},
+	Element, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
+};
+
+#[derive(Debug, Clone)]
+pub struct WillpowerComponent;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Message {
+	WillpowerChanged(u16),
+	Spend(Spend),
+	Vice,
+	Virtue,
+	Rest,
+}
+
+impl WillpowerComponent {
+	pub fn new() -> Self {
+		Self
+	}
+
+	pub fn update(
+		&mut self,
+		message: Message,
+		character: &mut Character,
+		extras: &mut CharacterExtras,
+	) {
+		match message {
+			Message::WillpowerChanged(willpower) => character.willpower = willpower,
+			Message::Spend(spend) => {
+				willpower::spend(character, extras, spend);
+			}
+			Message::Vice => willpower::indulge(character, extras),
+			Message::Virtue => willpower::uphold(character, extras),
+			Message::Rest => willpower::rest(character, extras),
+		}
+	}
+
+	pub fn view(
+		&self,
+		character: &Character,
+		extras: &CharacterExtras,
+		breakdown: &Breakdown,
+	) -> Element<Message> {
+		let dots = SheetDots::new(
+			character.willpower,
+			0,
+			modifiers::max_willpower(character, extras),
+			Shape::Dots,
+			None,
+			Message::WillpowerChanged,
+		);
+
+		let spend = |spend: Spend| {
+			button(text(spend.translated()))
+				.padding(INPUT_PADDING)
+				.on_press_maybe(
+					willpower::can_spend(character, extras).then_some(Message::Spend(spend)),
+				)
+		};
+
+		let vice = willpower::vice(character);
+		let virtue = willpower::virtue(character);
+
+		let mut parts =
+			breakdown.parts(character, |character| i32::from(character.max_willpower()));
+		parts.extend(modifiers::parts(character, extras, Target::Willpower));
+
+		column![
+			sources(text(fl!("willpower")).size(H3_SIZE), &parts),
+			dots,
+			row![spend(Spend::Dice), spend(Spend::Resistance)].spacing(3),
+		]
+		.push_maybe(
+			extras
+				.willpower_spend
+				.map(|spend| text(fl!("willpower-pending", spend = spend.translated()))),
+		)
+		.push(
+			row![
+				button(text(fl!("regain-vice", anchor = vice)))
+					.padding(INPUT_PADDING)
+					.on_press_maybe(
+						willpower::can_indulge(character, extras).then_some(Message::Vice)
+					),
+				button(text(fl!("regain-virtue", anchor = virtue)))
+					.padding(INPUT_PADDING)
+					.on_press_maybe(
+						willpower::can_uphold(character, extras).then_some(Message::Virtue)
+					),
+				button(text(fl!("regain-rest")))
+					.padding(INPUT_PADDING)
+					.on_press_maybe(
+						willpower::can_rest(character, extras).then_some(Message::Rest)
+					),
+			]
+			.spacing(3),
+		)
+		.spacing(TITLE_SPACING)
+		.align_items(Alignment::Center)
+		.into()
+	}
+}
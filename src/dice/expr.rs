@@ -0,0 +1,299 @@
+use std::fmt::{self, Display};
+
+use cofd::{character::Wound, prelude::*};
+
+use super::{pool, Pool};
+use crate::{extras::CharacterExtras, fl, i18n::Translate, modifiers, training};
+
+/// The result of evaluating a pool expression such as `Strength + Brawl + 2`.
+#[derive(Debug, Clone, Default)]
+pub struct Evaluation {
+	pub pool: Pool,
+	pub unknown: Vec<String>,
+	/// Skills the expression rolls, for anything that depends on them.
+	pub skills: Vec<Skill>,
+}
+
+impl Evaluation {
+	pub fn is_valid(&self) -> bool {
+		self.unknown.is_empty()
+	}
+}
+
+/// Lowercases and drops spaces and hyphens, so `Animal Ken`, `animal-ken` and `animalken` all match.
+fn normalize(str: &str) -> String {
+	str.chars()
+		.filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+		.flat_map(char::to_lowercase)
+		.collect()
+}
+
+fn resolve(
+	character: &Character,
+	pool: Pool,
+	skills: &mut Vec<Skill>,
+	term: &str,
+	negative: bool,
+) -> Result<Pool, Pool> {
+	let key = normalize(term);
+	let sign = if negative { -1 } else { 1 };
+
+	if let Ok(num) = term.parse::<i32>() {
+		return Ok(pool.bonus(fl!("modifier"), sign * num));
+	}
+
+	if let Some(attr) = pool::attributes()
+		.into_iter()
+		.find(|attr| normalize(&attr.translated()) == key || normalize(attr.name()) == key)
+	{
+		let val = i32::from(character.attributes().get(&attr));
+		return Ok(pool.bonus(attr.translated(), sign * val));
+	}
+
+	if let Some(skill) = Skill::all()
+		.into_iter()
+		.find(|skill| normalize(&skill.translated()) == key || normalize(skill.name()) == key)
+	{
+		return Ok(if negative {
+			let val = character.skills().get(skill) + training::skill_dot(character, skill);
+			pool.bonus(skill.translated(), -i32::from(val))
+		} else {
+			skills.push(skill);
+			pool.skill(character, skill)
+		});
+	}
+
+	if let Some((ability, val)) = character
+		.abilities
+		.iter()
+		.find(|(ability, _)| normalize(&ability.translated()) == key)
+	{
+		return Ok(pool.bonus(ability.translated(), sign * i32::from(*val)));
+	}
+
+	Err(pool)
+}
+
+/// Splits an expression into its terms, each with whether it's subtracted.
+///
+/// A hyphen between two letters is part of a name, like in `animal-ken`; anywhere else it's a
+/// minus.
+fn terms(expr: &str) -> Vec<(bool, String)> {
+	let mut terms = Vec::new();
+	let mut negative = false;
+	let mut term = String::new();
+	let mut chars = expr.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		let joined = c == '-'
+			&& term.chars().last().is_some_and(char::is_alphabetic)
+			&& chars.peek().is_some_and(|c| c.is_alphabetic());
+
+		if (c == '+' || c == '-') && !joined {
+			let trimmed = term.trim();
+			if !trimmed.is_empty() {
+				terms.push((negative, trimmed.to_string()));
+			}
+
+			term.clear();
+			negative = c == '-';
+		} else {
+			term.push(c);
+		}
+	}
+
+	let trimmed = term.trim();
+	if !trimmed.is_empty() {
+		terms.push((negative, trimmed.to_string()));
+	}
+
+	terms
+}
+
+/// Evaluates a free-text dice pool like `Dexterity + Firearms` against the character.
+///
+/// Terms are Attributes, Skills, the character's splat abilities or plain numbers, joined by
+/// `+` and `-`. Trait names are matched in the current language as well as by their key.
+pub fn evaluate(expr: &str, character: &Character, extras: &CharacterExtras) -> Evaluation {
+	let mut eval = Evaluation::default();
+	let mut skills = Vec::new();
+
+	for (negative, term) in terms(expr) {
+		eval.pool = match resolve(character, eval.pool, &mut skills, &term, negative) {
+			Ok(pool) => pool,
+			Err(pool) => {
+				eval.unknown.push(term);
+				pool
+			}
+		};
+	}
+
+	eval.pool = modifiers::roll_pool(eval.pool, &skills, character, extras)
+		.wound_penalty(character, extras);
+	eval.skills = skills;
+	eval
+}
+
+/// A weapon's damage rating, like `2L` or `1B`. It's added to the successes of a hit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeaponDamage {
+	pub rating: u16,
+	pub kind: Option<Wound>,
+}
+
+impl WeaponDamage {
+	pub fn parse(str: &str) -> Option<Self> {
+		let str = str.trim().trim_start_matches('+');
+		let digits: String = str.chars().take_while(char::is_ascii_digit).collect();
+		let rating = digits.parse().ok()?;
+
+		let kind = match str[digits.len()..].trim().chars().next() {
+			Some('B' | 'b') => Some(Wound::Bashing),
+			Some('L' | 'l') => Some(Wound::Lethal),
+			Some('A' | 'a') => Some(Wound::Aggravated),
+			_ => None,
+		};
+
+		Some(Self { rating, kind })
+	}
+
+	/// Damage dealt by a hit with `successes` successes, if it hit at all.
+	pub fn dealt(self, successes: u16) -> Option<Self> {
+		(successes > 0).then_some(Self {
+			rating: successes + self.rating,
+			kind: self.kind,
+		})
+	}
+}
+
+impl Display for WeaponDamage {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.kind {
+			Some(kind) => write!(f, "{} {}", self.rating, kind.translated()),
+			None => write!(f, "{}", self.rating),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cofd::splat::Merit;
+
+	use super::*;
+
+	fn character() -> Character {
+		Character::builder()
+			.with_attributes(Attributes {
+				strength: 3,
+				wits: 2,
+				..Default::default()
+			})
+			.with_skills(Skills {
+				brawl: 2,
+				animal_ken: 1,
+				..Default::default()
+			})
+			.build()
+	}
+
+	fn total(expr: &str) -> i32 {
+		evaluate(expr, &character(), &CharacterExtras::default())
+			.pool
+			.total()
+	}
+
+	#[test]
+	fn splits_on_plus_and_minus() {
+		assert_eq!(
+			terms("Strength + Brawl - 2"),
+			vec![
+				(false, "Strength".to_string()),
+				(false, "Brawl".to_string()),
+				(true, "2".to_string()),
+			]
+		);
+		assert_eq!(
+			terms("-1+wits"),
+			vec![(true, "1".to_string()), (false, "wits".to_string())]
+		);
+	}
+
+	#[test]
+	fn keeps_hyphens_inside_names() {
+		assert_eq!(
+			terms("wits+animal-ken-1"),
+			vec![
+				(false, "wits".to_string()),
+				(false, "animal-ken".to_string()),
+				(true, "1".to_string()),
+			]
+		);
+		assert_eq!(
+			terms("Wits - Brawl"),
+			vec![(false, "Wits".to_string()), (true, "Brawl".to_string())]
+		);
+	}
+
+	#[test]
+	fn adds_numeric_modifiers() {
+		assert_eq!(total("Strength + Brawl"), 5);
+		assert_eq!(total("Strength + Brawl + 2"), 7);
+		assert_eq!(total("Strength + Brawl - 2"), 3);
+		assert_eq!(total("Strength - Brawl"), 1);
+	}
+
+	#[test]
+	fn matches_multi_word_skills() {
+		assert_eq!(total("Wits + Animal Ken"), 3);
+		assert_eq!(total("wits + animal-ken"), 3);
+		assert_eq!(total("wits+animalken"), 3);
+	}
+
+	#[test]
+	fn counts_the_training_skill_dot_either_way() {
+		let character = Character::builder()
+			.with_attributes(Attributes {
+				wits: 2,
+				..Default::default()
+			})
+			.with_skills(Skills {
+				medicine: 1,
+				..Default::default()
+			})
+			.with_merits([(
+				Merit::ProfessionalTraining {
+					profession: "Doctor".to_string(),
+					skills: [Skill::Medicine, Skill::Science],
+					skill: Some(Skill::Medicine),
+				},
+				4,
+			)])
+			.build();
+		let total = |expr| {
+			evaluate(expr, &character, &CharacterExtras::default())
+				.pool
+				.total()
+		};
+
+		assert_eq!(total("Wits + Medicine"), 4);
+		assert_eq!(total("10 - Medicine"), 8);
+	}
+
+	#[test]
+	fn reports_unknown_names() {
+		let eval = evaluate(
+			"Strength + Fencing + 1",
+			&character(),
+			&CharacterExtras::default(),
+		);
+		assert!(!eval.is_valid());
+		assert_eq!(eval.unknown, vec!["Fencing".to_string()]);
+		assert_eq!(eval.pool.total(), 4);
+		assert!(evaluate(
+			"Strength + Brawl",
+			&character(),
+			&CharacterExtras::default()
+		)
+		.is_valid());
+	}
+}
@@ -0,0 +1,451 @@
+pub mod expr;
+pub mod pool;
+
+use std::fmt::{self, Display};
+
+pub use expr::WeaponDamage;
+pub use pool::{Pool, PoolSpec, PoolTrait};
+
+use crate::{
+	fl,
+	i18n::{self, Translate},
+};
+
+const EXCEPTIONAL_SUCCESS: u16 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Again {
+	#[default]
+	Ten,
+	Nine,
+	Eight,
+	No,
+}
+
+impl Again {
+	pub fn all() -> [Again; 4] {
+		[Self::Ten, Self::Nine, Self::Eight, Self::No]
+	}
+
+	fn threshold(self) -> Option<u8> {
+		match self {
+			Self::Ten => Some(10),
+			Self::Nine => Some(9),
+			Self::Eight => Some(8),
+			Self::No => None,
+		}
+	}
+
+	pub fn name(self) -> &'static str {
+		match self {
+			Self::Ten => "ten-again",
+			Self::Nine => "nine-again",
+			Self::Eight => "eight-again",
+			Self::No => "no-again",
+		}
+	}
+}
+
+impl Translate for Again {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(self.name())
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Roll {
+	pub pool: i32,
+	pub dice: Vec<u8>,
+	pub chance: bool,
+}
+
+impl Roll {
+	/// Rolls `pool` dice, falling back to a chance die when the pool is empty.
+	pub fn new(pool: i32, again: Again) -> Self {
+		let chance = pool < 1;
+		let mut dice = Vec::new();
+
+		if chance {
+			dice.push(fastrand::u8(1..=10));
+		} else {
+			let threshold = again.threshold();
+			let mut remaining = pool;
+
+			while remaining > 0 {
+				let face = fastrand::u8(1..=10);
+				dice.push(face);
+
+				if threshold.is_none_or(|threshold| face < threshold) {
+					remaining -= 1;
+				}
+			}
+		}
+
+		Self { pool, dice, chance }
+	}
+
+	pub fn successes(&self) -> u16 {
+		let target = if self.chance { 10 } else { 8 };
+		u16::try_from(self.dice.iter().filter(|&&die| die >= target).count()).unwrap_or(u16::MAX)
+	}
+
+	pub fn is_dramatic_failure(&self) -> bool {
+		self.chance && self.dice.first() == Some(&1)
+	}
+
+	pub fn is_exceptional(&self) -> bool {
+		self.successes() >= EXCEPTIONAL_SUCCESS
+	}
+}
+
+impl Display for Roll {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let dice: Vec<String> = self.dice.iter().map(ToString::to_string).collect();
+
+		write!(f, "[{}] ", dice.join(", "))?;
+		if self.is_dramatic_failure() {
+			f.write_str(&i18n::LANGUAGE_LOADER.get("dramatic-failure"))
+		} else if self.is_exceptional() {
+			write!(
+				f,
+				"{} ({})",
+				self.successes(),
+				i18n::LANGUAGE_LOADER.get("exceptional-success")
+			)
+		} else {
+			write!(f, "{}", self.successes())
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winner {
+	Actor,
+	Opponent,
+	Tie,
+}
+
+/// Two opposed rolls; whoever gets more successes wins.
+#[derive(Debug, Clone)]
+pub struct Contest {
+	pub actor: Roll,
+	pub opponent: Roll,
+}
+
+impl Contest {
+	/// Each side rolls with their own rerolls.
+	pub fn new(actor: (i32, Again), opponent: (i32, Again)) -> Self {
+		Self {
+			actor: Roll::new(actor.0, actor.1),
+			opponent: Roll::new(opponent.0, opponent.1),
+		}
+	}
+
+	pub fn winner(&self) -> Winner {
+		match self.actor.successes().cmp(&self.opponent.successes()) {
+			std::cmp::Ordering::Greater => Winner::Actor,
+			std::cmp::Ordering::Less => Winner::Opponent,
+			std::cmp::Ordering::Equal => Winner::Tie,
+		}
+	}
+}
+
+/// What a roll is up against.
+#[derive(Debug, Clone)]
+pub enum Opposition {
+	None,
+	/// The target's Resistance is subtracted from the pool.
+	Resisted {
+		name: String,
+		resistance: Pool,
+	},
+	/// The target rolls their own pool, most successes wins.
+	Contested {
+		name: String,
+		pool: Pool,
+		again: Again,
+	},
+}
+
+/// A roll with every pool already resolved against the sheet (and the roster, for opponents).
+#[derive(Debug, Clone)]
+pub struct Request {
+	pub name: String,
+	pub pool: Pool,
+	pub again: Again,
+	pub opposition: Opposition,
+	/// Weapon damage, added to the successes of an attack that hits.
+	pub damage: Option<WeaponDamage>,
+}
+
+impl Request {
+	pub fn roll(self) -> Outcome {
+		match self.opposition {
+			Opposition::None => Outcome::Simple {
+				roll: Roll::new(self.pool.total(), self.again),
+				name: self.name,
+				pool: self.pool,
+				damage: self.damage,
+			},
+			Opposition::Resisted { name, resistance } => {
+				let pool = self
+					.pool
+					.minus(fl!("resistance-of", name = name.clone()), &resistance);
+
+				Outcome::Resisted {
+					roll: Roll::new(pool.total(), self.again),
+					name: self.name,
+					pool,
+					opponent: name,
+					damage: self.damage,
+				}
+			}
+			Opposition::Contested { name, pool, again } => Outcome::Contested {
+				contest: Contest::new((self.pool.total(), self.again), (pool.total(), again)),
+				name: self.name,
+				pool: self.pool,
+				opponent: name,
+				opponent_pool: pool,
+			},
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum Outcome {
+	Simple {
+		name: String,
+		pool: Pool,
+		roll: Roll,
+		damage: Option<WeaponDamage>,
+	},
+	Resisted {
+		name: String,
+		pool: Pool,
+		roll: Roll,
+		opponent: String,
+		damage: Option<WeaponDamage>,
+	},
+	Contested {
+		name: String,
+		pool: Pool,
+		opponent: String,
+		opponent_pool: Pool,
+		contest: Contest,
+	},
+}
+
+impl Outcome {
+	/// Who came out on top, for rolls that had an opponent.
+	pub fn winner(&self) -> Option<Winner> {
+		match self {
+			Self::Simple { .. } => None,
+			Self::Resisted { roll, .. } => Some(if roll.successes() > 0 {
+				Winner::Actor
+			} else {
+				Winner::Opponent
+			}),
+			Self::Contested { contest, .. } => Some(contest.winner()),
+		}
+	}
+
+	/// The weapon damage dealt, if this was an attack that hit.
+	pub fn damage(&self) -> Option<WeaponDamage> {
+		match self {
+			Self::Simple {
+				roll,
+				damage: Some(damage),
+				..
+			}
+			| Self::Resisted {
+				roll,
+				damage: Some(damage),
+				..
+			} => damage.dealt(roll.successes()),
+			_ => None,
+		}
+	}
+
+	pub fn winner_name(&self) -> Option<String> {
+		let (name, opponent) = match self {
+			Self::Simple { .. } => return None,
+			Self::Resisted { name, opponent, .. } | Self::Contested { name, opponent, .. } => {
+				(name, opponent)
+			}
+		};
+
+		self.winner().map(|winner| match winner {
+			Winner::Actor => fl!("winner", name = name.clone()),
+			Winner::Opponent => fl!("winner", name = opponent.clone()),
+			Winner::Tie => fl!("tie"),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cofd::character::Wound;
+
+	use super::*;
+
+	fn roll(dice: &[u8], chance: bool) -> Roll {
+		Roll {
+			pool: i32::try_from(dice.len()).unwrap(),
+			dice: dice.to_vec(),
+			chance,
+		}
+	}
+
+	fn request(pool: i32, opposition: Opposition) -> Request {
+		Request {
+			name: "Actor".to_string(),
+			pool: Pool::new().bonus("Pool", pool),
+			again: Again::Ten,
+			opposition,
+			damage: Some(WeaponDamage {
+				rating: 2,
+				kind: Some(Wound::Lethal),
+			}),
+		}
+	}
+
+	#[test]
+	fn each_die_at_the_threshold_adds_another() {
+		fastrand::seed(7);
+
+		for again in Again::all() {
+			for _ in 0..50 {
+				let roll = Roll::new(6, again);
+				let rerolls = again.threshold().map_or(0, |threshold| {
+					roll.dice.iter().filter(|&&die| die >= threshold).count()
+				});
+
+				assert!(!roll.chance);
+				assert_eq!(roll.dice.len(), 6 + rerolls);
+				assert!(roll.dice.iter().all(|die| (1..=10).contains(die)));
+			}
+		}
+	}
+
+	#[test]
+	fn empty_pools_roll_a_chance_die() {
+		fastrand::seed(7);
+
+		for pool in [0, -3] {
+			let roll = Roll::new(pool, Again::Eight);
+			assert!(roll.chance);
+			assert_eq!(roll.dice.len(), 1);
+		}
+	}
+
+	#[test]
+	fn successes_count_eights_or_a_ten_on_a_chance_die() {
+		assert_eq!(roll(&[1, 7, 8, 9, 10, 10], false).successes(), 4);
+		assert!(!roll(&[8, 9, 10, 10], false).is_exceptional());
+		assert!(roll(&[8, 9, 10, 10, 8], false).is_exceptional());
+
+		assert_eq!(roll(&[9], true).successes(), 0);
+		assert_eq!(roll(&[10], true).successes(), 1);
+		assert!(roll(&[1], true).is_dramatic_failure());
+		assert!(!roll(&[1], false).is_dramatic_failure());
+	}
+
+	#[test]
+	fn the_contest_goes_to_more_successes() {
+		let contest = |actor: &[u8], opponent: &[u8]| {
+			Contest {
+				actor: roll(actor, false),
+				opponent: roll(opponent, false),
+			}
+			.winner()
+		};
+
+		assert_eq!(contest(&[8, 9], &[10]), Winner::Actor);
+		assert_eq!(contest(&[1, 9], &[10, 8]), Winner::Opponent);
+		assert_eq!(contest(&[8], &[10]), Winner::Tie);
+		assert_eq!(contest(&[1], &[2]), Winner::Tie);
+	}
+
+	#[test]
+	fn resistance_comes_off_the_pool() {
+		fastrand::seed(7);
+
+		let resisted = |resistance| {
+			request(
+				5,
+				Opposition::Resisted {
+					name: "Target".to_string(),
+					resistance: Pool::new().bonus("Resolve", resistance),
+				},
+			)
+			.roll()
+		};
+
+		let Outcome::Resisted { pool, roll, .. } = resisted(3) else {
+			panic!("expected a resisted roll");
+		};
+		assert_eq!(pool.total(), 2);
+		assert_eq!(roll.pool, 2);
+		assert!(!roll.chance);
+
+		let Outcome::Resisted { roll, .. } = resisted(7) else {
+			panic!("expected a resisted roll");
+		};
+		assert!(roll.chance);
+	}
+
+	#[test]
+	fn contests_roll_both_pools() {
+		fastrand::seed(7);
+
+		let outcome = request(
+			4,
+			Opposition::Contested {
+				name: "Target".to_string(),
+				pool: Pool::new().bonus("Pool", 3),
+				again: Again::No,
+			},
+		)
+		.roll();
+
+		let Outcome::Contested { contest, .. } = &outcome else {
+			panic!("expected a contested roll");
+		};
+		assert_eq!(contest.actor.pool, 4);
+		assert_eq!(contest.opponent.pool, 3);
+		assert_eq!(contest.opponent.dice.len(), 3);
+		assert_eq!(outcome.winner(), Some(contest.winner()));
+		assert_eq!(outcome.damage(), None);
+	}
+
+	#[test]
+	fn hits_deal_successes_plus_the_weapon() {
+		let resisted = |dice: &[u8]| Outcome::Resisted {
+			name: "Actor".to_string(),
+			pool: Pool::new(),
+			roll: roll(dice, false),
+			opponent: "Target".to_string(),
+			damage: Some(WeaponDamage {
+				rating: 2,
+				kind: Some(Wound::Lethal),
+			}),
+		};
+
+		let hit = resisted(&[8, 10, 3]);
+		assert_eq!(hit.winner(), Some(Winner::Actor));
+		assert_eq!(
+			hit.damage(),
+			Some(WeaponDamage {
+				rating: 4,
+				kind: Some(Wound::Lethal),
+			})
+		);
+
+		let miss = resisted(&[1, 7]);
+		assert_eq!(miss.winner(), Some(Winner::Opponent));
+		assert_eq!(miss.damage(), None);
+
+		let simple = request(3, Opposition::None).roll();
+		assert_eq!(simple.winner(), None);
+	}
+}
@@ -0,0 +1,247 @@
+use std::fmt::{self, Display};
+
+use cofd::prelude::*;
+
+use crate::{extras::CharacterExtras, fl, i18n::Translate, modifiers, training};
+
+const CATEGORIES: [TraitCategory; 3] = [
+	TraitCategory::Mental,
+	TraitCategory::Physical,
+	TraitCategory::Social,
+];
+
+/// Something a dice pool can be built out of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolTrait {
+	Attribute(Attribute),
+	Skill(Skill),
+}
+
+impl PoolTrait {
+	pub fn all() -> Vec<PoolTrait> {
+		let mut vec: Vec<PoolTrait> = attributes().into_iter().map(PoolTrait::Attribute).collect();
+
+		vec.extend(Skill::all().into_iter().map(PoolTrait::Skill));
+		vec
+	}
+}
+
+impl Translate for PoolTrait {
+	fn translated(&self) -> String {
+		match self {
+			Self::Attribute(attr) => attr.translated(),
+			Self::Skill(skill) => skill.translated(),
+		}
+	}
+}
+
+pub fn attributes() -> Vec<Attribute> {
+	CATEGORIES
+		.into_iter()
+		.flat_map(Attribute::get_by_category)
+		.collect()
+}
+
+pub fn skill_category(skill: Skill) -> TraitCategory {
+	CATEGORIES
+		.into_iter()
+		.find(|&category| {
+			Skill::get_by_category(category)
+				.into_iter()
+				.any(|s| s == skill)
+		})
+		.unwrap_or(TraitCategory::Mental)
+}
+
+/// A dice pool, kept as a list of labelled parts so the sheet can show where every die came from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Pool {
+	parts: Vec<(String, i32)>,
+}
+
+impl Pool {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn attribute(mut self, character: &Character, attr: Attribute) -> Self {
+		self.parts.push((
+			attr.translated(),
+			i32::from(character.attributes().get(&attr)),
+		));
+		self
+	}
+
+	/// Adds a skill, or the unskilled penalty for its category if the character has no dots in it.
+	pub fn skill(mut self, character: &Character, skill: Skill) -> Self {
+		let val = character.skills().get(skill) + training::skill_dot(character, skill);
+
+		if val == 0 {
+			let penalty = i32::from(skill_category(skill).unskilled());
+			self.parts.push((
+				format!("{} ({})", skill.translated(), fl!("unskilled-label")),
+				-penalty,
+			));
+		} else {
+			self.parts.push((skill.translated(), i32::from(val)));
+		}
+		self
+	}
+
+	pub fn with_trait(self, character: &Character, pool_trait: PoolTrait) -> Self {
+		match pool_trait {
+			PoolTrait::Attribute(attr) => self.attribute(character, attr),
+			PoolTrait::Skill(skill) => self.skill(character, skill),
+		}
+	}
+
+	pub fn supernatural_tolerance(mut self, character: &Character) -> Self {
+		if let Some(st) = character.splat.supernatural_tolerance() {
+			self.parts
+				.push((st.translated(), i32::from(character.power)));
+		}
+		self
+	}
+
+	pub fn wound_penalty(self, character: &Character, extras: &CharacterExtras) -> Self {
+		let wp = i32::from(modifiers::wound_penalty(character, extras));
+		self.bonus(fl!("wound-penalty"), -wp)
+	}
+
+	/// Adds a flat modifier. Zero modifiers are left out of the breakdown.
+	pub fn bonus(mut self, label: impl Into<String>, dice: i32) -> Self {
+		if dice != 0 {
+			self.parts.push((label.into(), dice));
+		}
+		self
+	}
+
+	/// Subtracts another pool as a single part, e.g. a target's resistance.
+	pub fn minus(self, label: impl Into<String>, other: &Pool) -> Self {
+		self.bonus(label, -other.total())
+	}
+
+	pub fn parts(&self) -> &[(String, i32)] {
+		&self.parts
+	}
+
+	pub fn total(&self) -> i32 {
+		self.parts.iter().map(|(_, dice)| dice).sum()
+	}
+
+	/// The Resistance a character offers against an action: one Resistance Attribute,
+	/// plus Supernatural Tolerance when resisting a supernatural power.
+	pub fn resistance(character: &Character, attr: Attribute, supernatural: bool) -> Self {
+		let pool = Self::new().attribute(character, attr);
+
+		if supernatural {
+			pool.supernatural_tolerance(character)
+		} else {
+			pool
+		}
+	}
+}
+
+impl Display for Pool {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.parts.is_empty() {
+			return f.write_str("0");
+		}
+
+		for (i, (label, dice)) in self.parts.iter().enumerate() {
+			match (i, dice.is_negative()) {
+				(0, false) => write!(f, "{label} {dice}")?,
+				(0, true) => write!(f, "-{label} {}", dice.abs())?,
+				(_, false) => write!(f, " + {label} {dice}")?,
+				(_, true) => write!(f, " - {label} {}", dice.abs())?,
+			}
+		}
+
+		write!(f, " = {}", self.total())
+	}
+}
+
+/// What the player picked to roll: an Attribute, an optional second trait, and a flat modifier.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PoolSpec {
+	pub attribute: Option<Attribute>,
+	pub other: Option<PoolTrait>,
+	pub modifier: i32,
+}
+
+impl PoolSpec {
+	pub fn build(&self, character: &Character, extras: &CharacterExtras) -> Pool {
+		let mut pool = Pool::new();
+
+		if let Some(attr) = self.attribute {
+			pool = pool.attribute(character, attr);
+		}
+		let mut skills = Vec::new();
+		if let Some(other) = self.other {
+			pool = pool.with_trait(character, other);
+
+			if let PoolTrait::Skill(skill) = other {
+				skills.push(skill);
+			}
+		}
+
+		modifiers::roll_pool(pool, &skills, character, extras)
+			.wound_penalty(character, extras)
+			.bonus(fl!("modifier"), self.modifier)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cofd::splat::vampire::{Clan, Vampire};
+
+	use super::*;
+
+	fn character(splat: Option<Vampire>) -> Character {
+		let mut builder = Character::builder().with_attributes(Attributes {
+			resolve: 3,
+			composure: 2,
+			..Default::default()
+		});
+		if let Some(splat) = splat {
+			builder = builder.with_splat(splat);
+		}
+
+		let mut character = builder.build();
+		character.power = 2;
+		character
+	}
+
+	#[test]
+	fn resistance_is_the_attribute() {
+		let mortal = character(None);
+		assert_eq!(
+			Pool::resistance(&mortal, Attribute::Resolve, false).total(),
+			3
+		);
+		assert_eq!(
+			Pool::resistance(&mortal, Attribute::Composure, false).total(),
+			2
+		);
+	}
+
+	#[test]
+	fn supernatural_powers_add_tolerance() {
+		let vampire = character(Some(Vampire::new(Clan::Ventrue, None, None)));
+		assert_eq!(
+			Pool::resistance(&vampire, Attribute::Resolve, false).total(),
+			3
+		);
+		assert_eq!(
+			Pool::resistance(&vampire, Attribute::Resolve, true).total(),
+			5
+		);
+
+		// Mortals have no Supernatural Tolerance to add.
+		let mortal = character(None);
+		assert_eq!(
+			Pool::resistance(&mortal, Attribute::Resolve, true).total(),
+			3
+		);
+	}
+}
@@ -0,0 +1,43 @@
+use cofd::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	aspirations::Aspiration,
+	health::Recovery,
+	history::Event,
+	items::{Armor, Item},
+	modifiers::Adjustment,
+	notes::Notes,
+	resources::Reduction,
+	session::Session,
+	specialties::Source,
+	tilts::Tilt,
+	willpower::Spend,
+};
+
+/// Per-character state the app keeps track of that has no place on `cofd::Character`.
+///
+/// Stored next to the roster under its own key, one entry per character in the same order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterExtras {
+	pub recovery: Recovery,
+	pub armor: Vec<Armor>,
+	pub items: Vec<Item>,
+	pub reductions: Vec<Reduction>,
+	pub tilts: Vec<Tilt>,
+	pub aspirations: Vec<Aspiration>,
+	pub history: Vec<Event>,
+	pub session: Session,
+	pub notes: Notes,
+	/// Keys of the Style maneuvers the character is using right now.
+	pub maneuvers: Vec<String>,
+	/// Specialties granted by Professional Training, kept apart from the ones bought with
+	/// experience.
+	pub specialties: Vec<(Skill, String)>,
+	/// Where the Specialties on the sheet came from, for those not taken at creation.
+	pub specialty_sources: Vec<(Skill, String, Source)>,
+	pub adjustments: Vec<Adjustment>,
+	/// Willpower spent on the character's next roll, or their next resistance.
+	pub willpower_spend: Option<Spend>,
+}
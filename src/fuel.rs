@@ -0,0 +1,126 @@
+use cofd::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Fuel that can be spent in a single turn, by Supernatural Tolerance. Every template
+/// (Blood Potency, Primal Urge, Gnosis, Wyrd, Synergy) follows the same table.
+const PER_TURN: [u16; 11] = [1, 1, 2, 3, 4, 5, 6, 7, 8, 10, 15];
+
+pub fn per_turn(character: &Character) -> u16 {
+	PER_TURN[usize::from(character.power).min(PER_TURN.len() - 1)]
+}
+
+/// Fuel spent so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FuelUse {
+	pub turn: u16,
+	pub scene: u16,
+}
+
+impl FuelUse {
+	pub fn record(&mut self, amount: u16) {
+		self.turn += amount;
+		self.scene += amount;
+	}
+
+	pub fn end_turn(&mut self) {
+		self.turn = 0;
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+	/// Spending it would go over the per-turn limit.
+	Limit(u16),
+	/// There isn't that much fuel left.
+	Empty,
+	/// The pool can't hold more.
+	Full,
+}
+
+/// Whether `amount` fuel can be spent this turn.
+pub fn check(character: &Character, used: &FuelUse, amount: u16) -> Result<(), Error> {
+	let limit = per_turn(character);
+	if used.turn + amount > limit {
+		return Err(Error::Limit(limit));
+	}
+	if amount > character.fuel {
+		return Err(Error::Empty);
+	}
+
+	Ok(())
+}
+
+/// Spends `amount` fuel, keeping to the per-turn limit.
+pub fn spend(character: &mut Character, used: &mut FuelUse, amount: u16) -> Result<(), Error> {
+	check(character, used, amount)?;
+
+	character.fuel -= amount;
+	used.record(amount);
+	Ok(())
+}
+
+/// Gains `amount` fuel. Anything over the maximum is lost, which is reported as an error.
+pub fn gain(character: &mut Character, amount: u16) -> Result<(), Error> {
+	let max = character.max_fuel();
+	let fuel = character.fuel.saturating_add(amount);
+
+	character.fuel = fuel.min(max);
+	if fuel > max {
+		Err(Error::Full)
+	} else {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cofd::splat::vampire::{Clan, Vampire};
+
+	use super::*;
+
+	fn vampire(power: u16, fuel: u16) -> Character {
+		let mut character = Character::builder()
+			.with_splat(Vampire::new(Clan::Ventrue, None, None))
+			.build();
+		character.power = power;
+		character.fuel = fuel;
+		character
+	}
+
+	#[test]
+	fn spend_keeps_to_the_turn_limit() {
+		let mut character = vampire(1, 5);
+		let mut used = FuelUse::default();
+
+		assert_eq!(spend(&mut character, &mut used, 1), Ok(()));
+		assert_eq!(spend(&mut character, &mut used, 1), Err(Error::Limit(1)));
+		assert_eq!(character.fuel, 4);
+
+		used.end_turn();
+		assert_eq!(spend(&mut character, &mut used, 1), Ok(()));
+		assert_eq!(used, FuelUse { turn: 1, scene: 2 });
+	}
+
+	#[test]
+	fn spend_needs_the_fuel() {
+		let mut character = vampire(5, 2);
+		let mut used = FuelUse::default();
+
+		assert_eq!(spend(&mut character, &mut used, 3), Err(Error::Empty));
+		assert_eq!(spend(&mut character, &mut used, 6), Err(Error::Limit(5)));
+		assert_eq!(character.fuel, 2);
+		assert_eq!(used, FuelUse::default());
+	}
+
+	#[test]
+	fn gain_stops_at_the_maximum() {
+		let mut character = vampire(1, 0);
+		let max = character.max_fuel();
+
+		assert_eq!(gain(&mut character, 1), Ok(()));
+		assert_eq!(character.fuel, 1);
+		assert_eq!(gain(&mut character, max), Err(Error::Full));
+		assert_eq!(character.fuel, max);
+	}
+}
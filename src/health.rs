@@ -0,0 +1,310 @@
+use cofd::{
+	character::{Damage, Wound},
+	prelude::*,
+	splat::Splat,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::fuel::{self, FuelUse};
+
+const HOUR: u32 = 60;
+const DAY: u32 = 24 * HOUR;
+const WEEK: u32 = 7 * DAY;
+/// Downtime between chapters, for natural healing.
+pub const NIGHT: u32 = 8 * HOUR;
+
+/// Vitae it takes a vampire to mend one point of aggravated damage.
+const VITAE_PER_AGGRAVATED: u16 = 5;
+
+/// Damage types from least to most severe; `Damage::poke` moves one box a step along this.
+const SEVERITY: [Wound; 4] = [
+	Wound::None,
+	Wound::Bashing,
+	Wound::Lethal,
+	Wound::Aggravated,
+];
+
+fn severity(wound: Wound) -> usize {
+	SEVERITY.iter().position(|w| *w == wound).unwrap_or(0)
+}
+
+/// How many boxes of the track are marked with exactly `wound`.
+pub fn count(damage: &Damage, max: usize, wound: Wound) -> usize {
+	(0..max).filter(|i| damage.get_i(*i) == wound).count()
+}
+
+/// How many boxes of the track are marked at all.
+pub fn damaged(damage: &Damage, max: usize) -> usize {
+	(0..max).filter(|i| damage.get_i(*i) != Wound::None).count()
+}
+
+fn is_full(damage: &Damage, max: usize) -> bool {
+	max == 0 || damage.get_i(max - 1) != Wound::None
+}
+
+/// Marks a single point of damage, upgrading existing wounds once the track is full.
+fn wound_once(damage: &mut Damage, max: usize, wound: Wound) {
+	let target = severity(wound);
+	if target == 0 {
+		return;
+	}
+
+	if is_full(damage, max) {
+		// Full track: the least severe wound that can still get worse is upgraded instead.
+		let Some(from) = (1..SEVERITY.len() - 1).find(|i| count(damage, max, SEVERITY[*i]) > 0)
+		else {
+			return;
+		};
+
+		for step in &SEVERITY[from..target.max(from + 1)] {
+			damage.poke(step);
+		}
+	} else {
+		for step in &SEVERITY[..target] {
+			damage.poke(step);
+		}
+	}
+}
+
+/// Applies `amount` points of damage of the given type to a health track of `max` boxes.
+pub fn wound(damage: &mut Damage, max: usize, wound: Wound, amount: u16) {
+	for _ in 0..amount {
+		wound_once(damage, max, wound);
+	}
+}
+
+/// Clears one box of the given type, if there is one.
+fn heal_once(damage: &mut Damage, max: usize, wound: Wound) -> bool {
+	let from = severity(wound);
+	if from == 0 || count(damage, max, wound) == 0 {
+		return false;
+	}
+
+	for step in &SEVERITY[from..] {
+		damage.poke(step);
+	}
+	true
+}
+
+/// Heals up to `amount` boxes of the given type.
+pub fn heal(damage: &mut Damage, max: usize, wound: Wound, amount: u16) {
+	for _ in 0..amount {
+		if !heal_once(damage, max, wound) {
+			break;
+		}
+	}
+}
+
+/// Wounds heal one box at a time, least severe first.
+pub fn next_to_heal(damage: &Damage, max: usize) -> Option<Wound> {
+	SEVERITY[1..]
+		.iter()
+		.find(|wound| count(damage, max, **wound) > 0)
+		.copied()
+}
+
+/// Minutes of rest it takes to heal one box of the given type.
+pub fn healing_time(wound: Wound) -> u32 {
+	match wound {
+		Wound::None => 0,
+		Wound::Bashing => 15,
+		Wound::Lethal => 2 * DAY,
+		Wound::Aggravated => WEEK,
+	}
+}
+
+/// Whether the character's splat recovers by resting. Vampires only heal by spending Vitae.
+pub fn heals_naturally(character: &Character) -> bool {
+	!matches!(character.splat, Splat::Vampire(..))
+}
+
+/// What mending the next wound with fuel heals and costs: one Vitae mends two bashing or one
+/// lethal, five mend one aggravated.
+fn fuel_cost(damage: &Damage, max: usize) -> Option<(Wound, u16, u16)> {
+	match next_to_heal(damage, max)? {
+		Wound::Bashing => Some((Wound::Bashing, 2, 1)),
+		Wound::Lethal => Some((Wound::Lethal, 1, 1)),
+		Wound::Aggravated => Some((Wound::Aggravated, 1, VITAE_PER_AGGRAVATED)),
+		Wound::None => None,
+	}
+}
+
+/// Whether the next wound can be mended with fuel, within what's left to spend this turn.
+pub fn can_fuel_heal(character: &Character, used: &FuelUse, max: usize) -> bool {
+	matches!(character.splat, Splat::Vampire(..))
+		&& fuel_cost(character.health(), max)
+			.is_some_and(|(_, _, cost)| fuel::check(character, used, cost).is_ok())
+}
+
+/// Heals through the splat's own means, e.g. a vampire spending Vitae, keeping to the
+/// per-turn limit.
+pub fn fuel_heal(character: &mut Character, used: &mut FuelUse, max: usize) -> bool {
+	if !matches!(character.splat, Splat::Vampire(..)) {
+		return false;
+	}
+
+	let Some((wound, boxes, cost)) = fuel_cost(character.health(), max) else {
+		return false;
+	};
+	if fuel::spend(character, used, cost).is_err() {
+		return false;
+	}
+
+	heal(character.health_mut(), max, wound, boxes);
+	true
+}
+
+/// Natural healing in progress.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recovery {
+	/// Minutes of rest already put towards the box that heals next.
+	pub progress: u32,
+}
+
+impl Recovery {
+	/// Rests for `minutes`, healing boxes as their time comes up.
+	pub fn rest(&mut self, damage: &mut Damage, max: usize, minutes: u32) {
+		let mut remaining = minutes;
+
+		while let Some(wound) = next_to_heal(damage, max) {
+			let needed = healing_time(wound).saturating_sub(self.progress);
+			if remaining < needed {
+				self.progress += remaining;
+				return;
+			}
+
+			remaining -= needed;
+			self.progress = 0;
+			heal_once(damage, max, wound);
+		}
+
+		self.progress = 0;
+	}
+
+	/// Minutes until the next box heals.
+	pub fn next(&self, damage: &Damage, max: usize) -> Option<u32> {
+		next_to_heal(damage, max).map(|wound| healing_time(wound).saturating_sub(self.progress))
+	}
+
+	/// Minutes of rest until the whole track is clear.
+	pub fn total(&self, damage: &Damage, max: usize) -> u32 {
+		let total: u32 = SEVERITY[1..]
+			.iter()
+			.map(|wound| {
+				healing_time(*wound) * u32::try_from(count(damage, max, *wound)).unwrap_or(0)
+			})
+			.sum();
+
+		total.saturating_sub(self.progress)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cofd::splat::vampire::{Clan, Vampire};
+
+	use super::*;
+
+	const MAX: usize = 7;
+
+	fn track(bashing: u16, lethal: u16, aggravated: u16) -> Damage {
+		let mut damage = Damage::default();
+		wound(&mut damage, MAX, Wound::Aggravated, aggravated);
+		wound(&mut damage, MAX, Wound::Lethal, lethal);
+		wound(&mut damage, MAX, Wound::Bashing, bashing);
+		damage
+	}
+
+	fn counts(damage: &Damage) -> [usize; 3] {
+		[
+			count(damage, MAX, Wound::Bashing),
+			count(damage, MAX, Wound::Lethal),
+			count(damage, MAX, Wound::Aggravated),
+		]
+	}
+
+	#[test]
+	fn wounds_fill_the_track() {
+		let damage = track(2, 1, 1);
+		assert_eq!(counts(&damage), [2, 1, 1]);
+		assert_eq!(damaged(&damage, MAX), 4);
+	}
+
+	#[test]
+	fn full_track_upgrades_wounds() {
+		let mut damage = track(7, 0, 0);
+		wound(&mut damage, MAX, Wound::Bashing, 1);
+		assert_eq!(counts(&damage), [MAX - 1, 1, 0]);
+
+		wound(&mut damage, MAX, Wound::Lethal, 1);
+		assert_eq!(counts(&damage), [MAX - 2, 2, 0]);
+
+		let mut damage = track(0, 7, 0);
+		wound(&mut damage, MAX, Wound::Lethal, 1);
+		assert_eq!(counts(&damage), [0, MAX - 1, 1]);
+	}
+
+	#[test]
+	fn heals_only_the_given_type() {
+		let mut damage = track(2, 1, 1);
+		heal(&mut damage, MAX, Wound::Bashing, 5);
+		assert_eq!(counts(&damage), [0, 1, 1]);
+
+		heal(&mut damage, MAX, Wound::Aggravated, 1);
+		assert_eq!(counts(&damage), [0, 1, 0]);
+	}
+
+	#[test]
+	fn least_severe_heals_first() {
+		assert_eq!(next_to_heal(&track(1, 1, 1), MAX), Some(Wound::Bashing));
+		assert_eq!(next_to_heal(&track(0, 1, 1), MAX), Some(Wound::Lethal));
+		assert_eq!(next_to_heal(&track(0, 0, 1), MAX), Some(Wound::Aggravated));
+		assert_eq!(next_to_heal(&Damage::default(), MAX), None);
+	}
+
+	#[test]
+	fn fuel_heal_keeps_to_the_turn_limit() {
+		let mut character = Character::builder()
+			.with_splat(Vampire::new(Clan::Ventrue, None, None))
+			.build();
+		character.power = 1;
+		character.fuel = 10;
+		*character.health_mut() = track(2, 0, 1);
+		let mut used = FuelUse::default();
+
+		assert!(fuel_heal(&mut character, &mut used, MAX));
+		assert_eq!(counts(character.health()), [0, 0, 1]);
+		assert_eq!(character.fuel, 9);
+
+		// Aggravated damage takes five Vitae, more than Blood Potency 1 allows in a turn.
+		used.end_turn();
+		assert!(!can_fuel_heal(&character, &used, MAX));
+		assert!(!fuel_heal(&mut character, &mut used, MAX));
+		assert_eq!(character.fuel, 9);
+
+		character.power = 5;
+		assert!(can_fuel_heal(&character, &used, MAX));
+		assert!(fuel_heal(&mut character, &mut used, MAX));
+		assert_eq!(counts(character.health()), [0, 0, 0]);
+		assert_eq!(character.fuel, 4);
+	}
+
+	#[test]
+	fn rest_heals_as_time_comes_up() {
+		let mut damage = track(2, 1, 0);
+		let mut recovery = Recovery::default();
+
+		recovery.rest(&mut damage, MAX, 20);
+		assert_eq!(counts(&damage), [1, 1, 0]);
+		assert_eq!(recovery.progress, 5);
+		assert_eq!(recovery.next(&damage, MAX), Some(10));
+
+		recovery.rest(&mut damage, MAX, 10 + DAY);
+		assert_eq!(counts(&damage), [0, 1, 0]);
+		assert_eq!(recovery.total(&damage, MAX), DAY);
+
+		recovery.rest(&mut damage, MAX, WEEK);
+		assert_eq!(counts(&damage), [0, 0, 0]);
+		assert_eq!(recovery, Recovery::default());
+	}
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	fl,
+	i18n::Translate,
+	session::End,
+	willpower::{Regain, Spend},
+};
+
+/// Something that happened to a character worth keeping a record of.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Event {
+	AspirationFulfilled {
+		aspiration: String,
+		beats: u16,
+	},
+	WillpowerSpent(Spend),
+	WillpowerRegained {
+		regain: Regain,
+		amount: u16,
+	},
+	Ended {
+		end: End,
+		beats: u16,
+		/// Boxes of damage healed.
+		healed: u16,
+		willpower: u16,
+	},
+}
+
+impl Translate for Event {
+	fn translated(&self) -> String {
+		match self {
+			Self::AspirationFulfilled { aspiration, beats } => {
+				let aspiration: &str = aspiration;
+				let beats: u16 = *beats;
+
+				fl!(
+					"event-aspiration-fulfilled",
+					aspiration = aspiration,
+					beats = beats
+				)
+			}
+			Self::WillpowerSpent(spend) => {
+				let spend = spend.translated();
+				fl!("event-willpower-spent", spend = spend)
+			}
+			Self::WillpowerRegained { regain, amount } => {
+				let amount: u16 = *amount;
+
+				match regain {
+					Regain::Vice(anchor) | Regain::Virtue(anchor) => {
+						let anchor: &str = anchor;
+						fl!("event-willpower-anchor", anchor = anchor, amount = amount)
+					}
+					Regain::Rest => fl!("event-willpower-rest", amount = amount),
+				}
+			}
+			Self::Ended {
+				end,
+				beats,
+				healed,
+				willpower,
+			} => {
+				let end = end.translated();
+				let (beats, healed, willpower): (u16, u16, u16) = (*beats, *healed, *willpower);
+
+				fl!(
+					"event-ended",
+					end = end,
+					beats = beats,
+					healed = healed,
+					willpower = willpower
+				)
+			}
+		}
+	}
+}
@@ -7,7 +7,7 @@ use std::{
 };
 
 use cofd::{
-	character::InfoTrait,
+	character::{InfoTrait, Wound},
 	prelude::{Template, VariantName},
 	splat::{
 		ability::Ability,
@@ -88,6 +88,13 @@ pub fn setup() -> anyhow::Result<Box<dyn LanguageRequester<'static>>> {
 	Ok(language_requester)
 }
 
+/// An attribute of a message, if there is one.
+pub fn attr(key: &str, attr: &str) -> Option<String> {
+	LANGUAGE_LOADER
+		.has_attr(key, attr)
+		.then(|| LANGUAGE_LOADER.get_attr(key, attr))
+}
+
 pub trait Translate {
 	fn translated(&self) -> String;
 }
@@ -309,3 +316,14 @@ impl Translate for SplatKind {
 		LANGUAGE_LOADER.get(self.name())
 	}
 }
+
+impl Translate for Wound {
+	fn translated(&self) -> String {
+		LANGUAGE_LOADER.get(match self {
+			Wound::None => "none",
+			Wound::Bashing => "bashing",
+			Wound::Lethal => "lethal",
+			Wound::Aggravated => "aggravated",
+		})
+	}
+}
@@ -0,0 +1,57 @@
+use cofd::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{i18n, i18n::Translate};
+
+/// A piece of armor the character owns. Only equipped armor counts towards their traits.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Armor {
+	pub name: String,
+	pub general: u16,
+	pub ballistic: u16,
+	/// Minimum Strength to wear it without a penalty to combat pools.
+	pub strength: u16,
+	pub defense: u16,
+	pub speed: u16,
+	pub coverage: String,
+	pub equipped: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Carry {
+	Equipped,
+	#[default]
+	Carried,
+	Stashed,
+}
+
+impl Carry {
+	pub fn all() -> [Carry; 3] {
+		[Self::Equipped, Self::Carried, Self::Stashed]
+	}
+}
+
+impl Translate for Carry {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(match self {
+			Self::Equipped => "equipped",
+			Self::Carried => "carried",
+			Self::Stashed => "stashed",
+		})
+	}
+}
+
+/// A piece of general gear. Equipped gear adds its bonus to rolls of its Skill.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Item {
+	pub name: String,
+	pub size: u16,
+	pub durability: u16,
+	pub structure: u16,
+	pub availability: u16,
+	pub skill: Option<Skill>,
+	pub bonus: i16,
+	pub state: Carry,
+}
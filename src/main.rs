@@ -19,21 +19,41 @@ use std::{
 use cofd::prelude::*;
 use iced::{
 	executor,
-	widget::{button, column},
-	Alignment, Application, Command, Element, Length, Settings, Theme,
+	keyboard::{self, key::Named, Key},
+	widget::{button, column, focus_next, focus_previous, row},
+	Alignment, Application, Command, Element, Length, Settings, Subscription, Theme,
 };
 #[cfg(target_arch = "wasm32")]
 use log::Level;
 
+mod aspirations;
+mod caps;
+mod catalog;
 mod component;
+mod dice;
+mod extras;
+mod fuel;
+mod health;
+mod history;
 mod i18n;
+mod items;
+mod modifiers;
+mod notes;
+mod resources;
+mod session;
+mod specialties;
 mod store;
+mod tilts;
+mod training;
 mod view;
 mod widget;
+mod willpower;
 
+use extras::CharacterExtras;
 use store::Store;
+use willpower::Spend;
 
-use crate::view::{character_list::CharacterList, sheet::SheetView, *};
+use crate::view::{character_list::CharacterList, encounter::EncounterView, sheet::SheetView, *};
 
 const H2_SIZE: u16 = 25;
 const H3_SIZE: u16 = 20;
@@ -48,12 +68,14 @@ pub enum View {
 	CharacterList(character_list::CharacterList),
 	CharacterCreator,
 	Sheet(sheet::SheetView, usize),
+	Encounter(encounter::EncounterView),
 }
 
 struct PlayerCompanionApp {
 	view: View,
 	prev_view: Option<View>,
 	characters: Vec<Character>,
+	extras: Vec<CharacterExtras>,
 
 	store: Store,
 	// locale: Locale,
@@ -64,9 +86,13 @@ struct PlayerCompanionApp {
 enum Message {
 	AddCharacter(Character),
 	NewCharacter,
+	NewEncounter,
+	/// Tab moves keyboard focus forward, Shift+Tab back.
+	Focus(bool),
 
 	CharacterList(character_list::Message),
 	Sheet(sheet::Message),
+	Encounter(encounter::Message),
 }
 
 impl Debug for Message {
@@ -88,14 +114,23 @@ impl PlayerCompanionApp {
 
 	pub fn save(&self) -> anyhow::Result<()> {
 		self.store.set("characters", &self.characters)?;
+		self.store.set("extras", &self.extras)?;
 		Ok(())
 	}
 
 	pub fn load(&mut self) -> anyhow::Result<()> {
-		let characters: Vec<Character> = self
-			.store
-			.get("characters")?
-			.unwrap_or_else(demo::characters);
+		let (characters, extras): (Vec<Character>, Vec<CharacterExtras>) =
+			match self.store.get("characters")? {
+				// Older saves have no extras yet.
+				Some(characters) => (
+					characters,
+					self.store
+						.get("extras")
+						.unwrap_or_default()
+						.unwrap_or_default(),
+				),
+				None => (demo::characters(), demo::extras()),
+			};
 
 		self.characters = characters
 			.into_iter()
@@ -104,6 +139,14 @@ impl PlayerCompanionApp {
 			})
 			.collect();
 
+		self.extras = extras;
+		self.extras
+			.resize_with(self.characters.len(), Default::default);
+
+		for (character, extras) in self.characters.iter().zip(&mut self.extras) {
+			aspirations::migrate(character, extras);
+		}
+
 		Ok(())
 	}
 }
@@ -128,6 +171,7 @@ impl Application for PlayerCompanionApp {
 			// state: State::CharacterCreator,
 			prev_view: Default::default(),
 			characters: Vec::new(),
+			extras: Vec::new(),
 			store,
 			// custom_xsplats: vec![
 			// 	// My OC (Original Clan) (Do Not Steal)
@@ -157,6 +201,14 @@ impl Application for PlayerCompanionApp {
 
 	fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
 		match message {
+			Message::Focus(forward) => {
+				return if forward {
+					focus_next()
+				} else {
+					focus_previous()
+				};
+			}
+
 			Message::CharacterList(message) => {
 				if let View::CharacterList(view) = &mut self.view {
 					let character_list::Action::PickCharacter(i) = view.update(message);
@@ -174,17 +226,49 @@ impl Application for PlayerCompanionApp {
 
 			Message::Sheet(message) => {
 				if let View::Sheet(view, character) = &mut self.view {
-					view.update(message, self.characters.get_mut(*character).unwrap());
+					let rolled = view.rolled(&message);
+
+					view.update(
+						message,
+						self.characters.get_mut(*character).unwrap(),
+						self.extras.get_mut(*character).unwrap(),
+					);
+
+					// Willpower spent on a roll is gone once it's rolled, the opponent's too.
+					if let Some(rolled) = rolled {
+						willpower::used(self.extras.get_mut(*character).unwrap(), Spend::Dice);
+						if let Some((i, spend)) = rolled.opponent
+							&& let Some(extras) = self.extras.get_mut(i)
+						{
+							willpower::used(extras, spend);
+						}
+					}
+				}
+			}
+
+			Message::Encounter(encounter::Message::Back) => self.prev(),
+			Message::Encounter(encounter::Message::Save) => {
+				if let Err(err) = self.save() {
+					log::error!("{}", err);
+				}
+			}
+			Message::Encounter(message) => {
+				if let View::Encounter(view) = &mut self.view {
+					view.update(message, &mut self.characters, &mut self.extras);
 				}
 			}
 
 			Message::AddCharacter(character) => {
 				self.characters.push(character);
+				self.extras.push(CharacterExtras::default());
 				self.next(View::CharacterList(CharacterList::new()));
 			}
 			Message::NewCharacter => {
 				self.next(View::CharacterCreator);
 			}
+			Message::NewEncounter => {
+				self.next(View::Encounter(EncounterView::new()));
+			}
 		}
 
 		#[cfg(target_arch = "wasm32")]
@@ -201,11 +285,22 @@ impl Application for PlayerCompanionApp {
 		Command::none()
 	}
 
+	fn subscription(&self) -> Subscription<Self::Message> {
+		keyboard::on_key_press(|key, modifiers| match key {
+			Key::Named(Named::Tab) => Some(Message::Focus(!modifiers.shift())),
+			_ => None,
+		})
+	}
+
 	fn view(&self) -> Element<Self::Message, Self::Theme> {
 		match &self.view {
 			View::CharacterList(view) => column![
 				view.view(&self.characters).map(Message::CharacterList),
-				button("New Character").on_press(Message::NewCharacter)
+				row![
+					button("New Character").on_press(Message::NewCharacter),
+					button("Encounter").on_press(Message::NewEncounter),
+				]
+				.spacing(5)
 			]
 			.width(Length::Fill)
 			.align_items(Alignment::Center)
@@ -213,8 +308,16 @@ impl Application for PlayerCompanionApp {
 
 			View::CharacterCreator => view::creator_view(Message::AddCharacter).into(),
 			View::Sheet(view, character) => view
-				.view(self.characters.get(*character).unwrap())
+				.view(
+					self.characters.get(*character).unwrap(),
+					self.extras.get(*character).unwrap(),
+					&self.characters,
+					&self.extras,
+				)
 				.map(Message::Sheet),
+			View::Encounter(view) => view
+				.view(&self.characters, &self.extras)
+				.map(Message::Encounter),
 		}
 	}
 }
@@ -226,6 +329,10 @@ fn main() -> anyhow::Result<()> {
 	{
 		console_log::init_with_level(Level::Warn).map_err(|err| anyhow::anyhow!(err))?;
 		std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+
+		let mut seed = [0u8; 8];
+		getrandom::getrandom(&mut seed).map_err(|err| anyhow::anyhow!(err))?;
+		fastrand::seed(u64::from_le_bytes(seed));
 	}
 
 	PlayerCompanionApp::run(Settings {
@@ -241,6 +348,7 @@ mod demo {
 		splat::{changeling::*, geist::*, mage::*, vampire::*, werewolf::*, Merit},
 	};
 
+	use crate::extras::CharacterExtras;
 	#[allow(unused_imports)]
 	use crate::store::Store;
 
@@ -254,6 +362,24 @@ mod demo {
 		Ok(())
 	}
 
+	/// App-side state for the demo characters, in the same order.
+	pub fn extras() -> Vec<CharacterExtras> {
+		let mage_extras = CharacterExtras {
+			// Professional Training's free Specialties.
+			specialties: vec![
+				(Skill::Investigation, String::from("Riddles")),
+				(Skill::Science, String::from("Astronomy")),
+			],
+			..Default::default()
+		};
+
+		vec![
+			CharacterExtras::default(),
+			CharacterExtras::default(),
+			mage_extras,
+		]
+	}
+
 	#[allow(clippy::too_many_lines)]
 	pub fn characters() -> Vec<Character> {
 		let character = Character::builder().build();
@@ -481,9 +607,6 @@ mod demo {
 			.with_specialties(Skill::Academics, vec![String::from("Research")])
 			.with_specialties(Skill::AnimalKen, vec![String::from("Felines")])
 			.with_specialties(Skill::Subterfuge, vec![String::from("Detecting Lies")])
-			// TODO: Professional Training specialties
-			.with_specialties(Skill::Investigation, vec![String::from("Riddles")])
-			.with_specialties(Skill::Science, vec![String::from("Astronomy")])
 			.with_abilities([
 				(Arcanum::Mind.into(), 1),
 				(Arcanum::Prime.into(), 2),
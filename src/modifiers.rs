@@ -0,0 +1,578 @@
+use cofd::{
+	character::Wound,
+	prelude::*,
+	splat::{werewolf::Form, Splat},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	catalog::{conditions, styles, tilts, Effect, Scale},
+	dice::{pool, Pool},
+	extras::CharacterExtras,
+	fl,
+	i18n::{self, Translate},
+	items::Carry,
+	willpower,
+};
+
+/// What an app-side modifier applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Target {
+	Size,
+	Health,
+	Willpower,
+	Speed,
+	Defense,
+	Initiative,
+	GeneralArmor,
+	BallisticArmor,
+	/// Every combat dice pool.
+	Combat,
+	/// Every dice pool that uses the Skill.
+	Skill(Skill),
+	/// Every dice pool using a Skill of the category.
+	MentalRolls,
+	PhysicalRolls,
+	SocialRolls,
+	/// Every dice pool.
+	AllRolls,
+}
+
+impl Translate for Target {
+	fn translated(&self) -> String {
+		match self {
+			Self::Skill(skill) => skill.translated(),
+			_ => i18n::LANGUAGE_LOADER.get(match self {
+				Self::Size => "size",
+				Self::Health => "health",
+				Self::Willpower => "willpower",
+				Self::Speed => "speed",
+				Self::Defense => "defense",
+				Self::Initiative => "initiative",
+				Self::GeneralArmor => "general-armor",
+				Self::BallisticArmor => "ballistic-armor",
+				Self::Combat => "combat-rolls",
+				Self::MentalRolls => "mental-rolls",
+				Self::PhysicalRolls => "physical-rolls",
+				Self::SocialRolls => "social-rolls",
+				Self::AllRolls | Self::Skill(_) => "all-rolls",
+			}),
+		}
+	}
+}
+
+impl Target {
+	/// Traits a manual adjustment can be made to.
+	pub fn adjustable() -> [Target; 8] {
+		[
+			Self::Size,
+			Self::Speed,
+			Self::Defense,
+			Self::Initiative,
+			Self::Health,
+			Self::Willpower,
+			Self::GeneralArmor,
+			Self::BallisticArmor,
+		]
+	}
+
+	fn rolls(category: TraitCategory) -> Self {
+		match category {
+			TraitCategory::Physical => Self::PhysicalRolls,
+			TraitCategory::Social => Self::SocialRolls,
+			_ => Self::MentalRolls,
+		}
+	}
+}
+
+/// A change to a trait made by hand, for house rules or powers nothing else models.
+///
+/// These are kept with the character's extras rather than in cofd's modifier map, which is
+/// rebuilt from Merits and abilities alone; see [`modifiers`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Adjustment {
+	pub name: String,
+	pub target: Target,
+	pub value: i16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Modifier {
+	pub source: String,
+	pub target: Target,
+	pub value: i16,
+}
+
+impl Modifier {
+	fn new(source: impl Into<String>, target: Target, value: i16) -> Self {
+		Self {
+			source: source.into(),
+			target,
+			value,
+		}
+	}
+
+	/// Halving or removing a trait is worked out from its value with `others` applied, so it
+	/// takes armor, adjustments and any other flat changes into account.
+	fn scaled(source: String, effect: Effect, character: &Character, others: &[Modifier]) -> Self {
+		let base = match effect.target {
+			Target::Speed => character.speed(),
+			Target::Defense => character.defense(),
+			_ => 0,
+		};
+		let base = apply(
+			base,
+			others
+				.iter()
+				.filter(|modifier| modifier.target == effect.target)
+				.map(|modifier| modifier.value)
+				.sum(),
+		);
+
+		let value = match effect.scale {
+			Scale::Flat => effect.value,
+			Scale::Half => -signed(base - base / 2),
+			Scale::All => -signed(base),
+		};
+
+		Self::new(source, effect.target, value)
+	}
+}
+
+/// Adds a flat effect straight away, and holds back one that halves or removes a trait until
+/// everything else is in.
+fn add_effect(
+	vec: &mut Vec<Modifier>,
+	scaled: &mut Vec<(String, Effect)>,
+	source: String,
+	effect: Effect,
+) {
+	if effect.scale == Scale::Flat {
+		vec.push(Modifier::new(source, effect.target, effect.value));
+	} else {
+		scaled.push((source, effect));
+	}
+}
+
+/// How many of the last Health boxes give a wound penalty when they're marked.
+const WOUNDED_BOXES: usize = 3;
+
+fn signed(val: u16) -> i16 {
+	i16::try_from(val).unwrap_or(i16::MAX)
+}
+
+/// Modifiers from state the app tracks itself, layered on top of the ones cofd
+/// works out from merits and abilities.
+///
+/// cofd rebuilds its modifier map from merits and abilities alone in `calc_mod_map`, and has no
+/// way to add anything else to it. Armor, gear, Conditions, Tilts, maneuvers and adjustments are
+/// therefore kept here instead, so only the helpers below see them: read derived traits through
+/// [`size`], [`defense`], [`speed`], [`armor`] and the rest rather than cofd's getters.
+pub fn modifiers(character: &Character, extras: &CharacterExtras) -> Vec<Modifier> {
+	let mut vec = Vec::new();
+	let mut scaled = Vec::new();
+
+	for armor in extras.armor.iter().filter(|armor| armor.equipped) {
+		let name = if armor.name.is_empty() {
+			fl!("armor")
+		} else {
+			armor.name.clone()
+		};
+
+		vec.push(Modifier::new(
+			name.clone(),
+			Target::GeneralArmor,
+			signed(armor.general),
+		));
+		vec.push(Modifier::new(
+			name.clone(),
+			Target::BallisticArmor,
+			signed(armor.ballistic),
+		));
+		vec.push(Modifier::new(
+			name.clone(),
+			Target::Defense,
+			-signed(armor.defense),
+		));
+		vec.push(Modifier::new(
+			name.clone(),
+			Target::Speed,
+			-signed(armor.speed),
+		));
+
+		let strength = character.attributes().get(&Attribute::Strength);
+		if strength < armor.strength {
+			vec.push(Modifier::new(
+				fl!("armor-too-heavy", name = name),
+				Target::Combat,
+				-signed(armor.strength - strength),
+			));
+		}
+	}
+
+	for item in extras
+		.items
+		.iter()
+		.filter(|item| item.state == Carry::Equipped)
+	{
+		if let Some(skill) = item.skill {
+			vec.push(Modifier::new(
+				item.name.clone(),
+				Target::Skill(skill),
+				item.bonus,
+			));
+		}
+	}
+
+	for key in &character.conditions {
+		if let Some(condition) = conditions::get(key) {
+			for effect in &condition.effects {
+				add_effect(&mut vec, &mut scaled, condition.translated(), *effect);
+			}
+		}
+	}
+
+	for tilt in &extras.tilts {
+		if let Some(entry) = tilts::get(&tilt.key) {
+			for effect in &entry.effects {
+				add_effect(&mut vec, &mut scaled, entry.translated(), *effect);
+			}
+		}
+	}
+
+	for maneuver in styles::unlocked(character) {
+		if maneuver.passive || extras.maneuvers.contains(&maneuver.key) {
+			for effect in &maneuver.effects {
+				add_effect(&mut vec, &mut scaled, maneuver.translated(), *effect);
+			}
+		}
+	}
+
+	for adjustment in &extras.adjustments {
+		let name = if adjustment.name.is_empty() {
+			fl!("adjustment")
+		} else {
+			fl!("adjustment-named", name = adjustment.name.as_str())
+		};
+
+		vec.push(Modifier::new(name, adjustment.target, adjustment.value));
+	}
+
+	// Each one sees the ones before it, so halving twice leaves a quarter.
+	for (source, effect) in scaled {
+		let modifier = Modifier::scaled(source, effect, character, &vec);
+		vec.push(modifier);
+	}
+
+	vec.retain(|modifier| modifier.value != 0);
+	vec
+}
+
+/// The app-side modifiers to `target`, one part per source.
+pub fn parts(
+	character: &Character,
+	extras: &CharacterExtras,
+	target: Target,
+) -> Vec<(String, i32)> {
+	modifiers(character, extras)
+		.into_iter()
+		.filter(|modifier| modifier.target == target)
+		.map(|modifier| (modifier.source, i32::from(modifier.value)))
+		.collect()
+}
+
+/// Where cofd's modifiers to the character's traits come from.
+///
+/// cofd only keeps a total per trait, so each Merit, ability and werewolf form is credited with
+/// whatever changes when the character is worked out without it.
+pub struct Breakdown {
+	/// The character with none of the sources.
+	bare: Character,
+	without: Vec<(String, Character)>,
+}
+
+impl Breakdown {
+	pub fn new(character: &Character) -> Self {
+		let strip = |f: &dyn Fn(&mut Character)| {
+			let mut character = character.clone();
+			f(&mut character);
+			character.calc_mod_map();
+			character
+		};
+
+		let mut without = Vec::new();
+		for (i, (merit, _)) in character.merits.iter().enumerate() {
+			without.push((
+				merit.translated(),
+				strip(&|character| {
+					character.merits.remove(i);
+				}),
+			));
+		}
+		for ability in character.abilities.keys() {
+			without.push((
+				ability.translated(),
+				strip(&|character| {
+					character.abilities.remove(ability);
+				}),
+			));
+		}
+		if let Splat::Werewolf(data) = &character.splat
+			&& data.form != Form::Hishu
+		{
+			without.push((data.form.translated(), strip(&default_form)));
+		}
+
+		let bare = strip(&|character| {
+			character.merits.clear();
+			character.abilities.clear();
+			default_form(character);
+		});
+
+		Self { bare, without }
+	}
+
+	/// Every source that changes the trait `f` reads, then whatever is left that none of them
+	/// explains alone.
+	pub fn parts(
+		&self,
+		character: &Character,
+		f: impl Fn(&Character) -> i32,
+	) -> Vec<(String, i32)> {
+		let value = f(character);
+
+		let mut parts: Vec<(String, i32)> = self
+			.without
+			.iter()
+			.map(|(source, without)| (source.clone(), value - f(without)))
+			.filter(|(_, value)| *value != 0)
+			.collect();
+
+		let rest = value - f(&self.bare) - parts.iter().map(|(_, value)| value).sum::<i32>();
+		if rest != 0 {
+			parts.push((fl!("other"), rest));
+		}
+
+		parts
+	}
+}
+
+fn default_form(character: &mut Character) {
+	if let Splat::Werewolf(data) = &mut character.splat {
+		data.form = Form::Hishu;
+	}
+}
+
+pub fn total(character: &Character, extras: &CharacterExtras, target: Target) -> i16 {
+	modifiers(character, extras)
+		.iter()
+		.filter(|modifier| modifier.target == target)
+		.map(|modifier| modifier.value)
+		.sum()
+}
+
+fn apply(base: u16, modifier: i16) -> u16 {
+	u16::try_from((i32::from(base) + i32::from(modifier)).max(0)).unwrap_or(u16::MAX)
+}
+
+pub fn size(character: &Character, extras: &CharacterExtras) -> u16 {
+	apply(character.size(), total(character, extras, Target::Size))
+}
+
+/// Health boxes; a change in Size changes Health along with it.
+pub fn max_health(character: &Character, extras: &CharacterExtras) -> u16 {
+	apply(
+		character.max_health(),
+		total(character, extras, Target::Health) + total(character, extras, Target::Size),
+	)
+}
+
+/// Damage in any of the last three Health boxes takes a die off every roll, so it moves with
+/// the boxes modifiers add or take away.
+pub fn wound_penalty(character: &Character, extras: &CharacterExtras) -> u16 {
+	let max = usize::from(max_health(character, extras));
+	let damaged = (max.saturating_sub(WOUNDED_BOXES)..max)
+		.filter(|i| character.health().get_i(*i) != Wound::None)
+		.count();
+
+	u16::try_from(damaged).unwrap_or(u16::MAX)
+}
+
+pub fn max_willpower(character: &Character, extras: &CharacterExtras) -> u16 {
+	apply(
+		character.max_willpower(),
+		total(character, extras, Target::Willpower),
+	)
+}
+
+pub fn speed(character: &Character, extras: &CharacterExtras) -> u16 {
+	apply(character.speed(), total(character, extras, Target::Speed))
+}
+
+pub fn defense(character: &Character, extras: &CharacterExtras) -> u16 {
+	apply(
+		character.defense(),
+		total(character, extras, Target::Defense),
+	)
+}
+
+/// Initiative modifier, before any weapon penalty.
+pub fn initiative(character: &Character, extras: &CharacterExtras) -> i16 {
+	signed(character.initiative()) + total(character, extras, Target::Initiative)
+}
+
+/// General and ballistic armor.
+pub fn armor(character: &Character, extras: &CharacterExtras) -> (u16, u16) {
+	let armor = character.armor();
+
+	(
+		apply(
+			armor.general,
+			total(character, extras, Target::GeneralArmor),
+		),
+		apply(
+			armor.ballistic,
+			total(character, extras, Target::BallisticArmor),
+		),
+	)
+}
+
+fn pool_modifiers(
+	pool: Pool,
+	targets: &[Target],
+	character: &Character,
+	extras: &CharacterExtras,
+) -> Pool {
+	modifiers(character, extras)
+		.into_iter()
+		.filter(|modifier| targets.contains(&modifier.target))
+		.fold(pool, |pool, modifier| {
+			pool.bonus(modifier.source, i32::from(modifier.value))
+		})
+}
+
+/// Adds every modifier to combat pools, one part per source.
+pub fn combat_pool(pool: Pool, character: &Character, extras: &CharacterExtras) -> Pool {
+	pool_modifiers(pool, &[Target::Combat], character, extras)
+}
+
+/// Adds equipment bonuses, Conditions and other modifiers to a roll using `skills`.
+pub fn roll_pool(
+	pool: Pool,
+	skills: &[Skill],
+	character: &Character,
+	extras: &CharacterExtras,
+) -> Pool {
+	let mut targets = vec![Target::AllRolls];
+	for skill in skills {
+		targets.push(Target::Skill(*skill));
+
+		let category = Target::rolls(pool::skill_category(*skill));
+		if !targets.contains(&category) {
+			targets.push(category);
+		}
+	}
+
+	willpower::dice(pool_modifiers(pool, &targets, character, extras), extras)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{health, items::Armor, tilts::Tilt};
+
+	fn character(damage: u16) -> Character {
+		let mut character = Character::builder()
+			.with_attributes(Attributes {
+				stamina: 2,
+				..Default::default()
+			})
+			.build();
+		let max = usize::from(character.max_health());
+		health::wound(character.health_mut(), max, Wound::Lethal, damage);
+		character
+	}
+
+	fn adjusted(target: Target, value: i16) -> CharacterExtras {
+		CharacterExtras {
+			adjustments: vec![Adjustment {
+				name: String::new(),
+				target,
+				value,
+			}],
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn wound_penalty_counts_the_last_three_boxes() {
+		let extras = CharacterExtras::default();
+		assert_eq!(max_health(&character(0), &extras), 7);
+
+		assert_eq!(wound_penalty(&character(4), &extras), 0);
+		assert_eq!(wound_penalty(&character(5), &extras), 1);
+		assert_eq!(wound_penalty(&character(6), &extras), 2);
+		assert_eq!(wound_penalty(&character(7), &extras), 3);
+	}
+
+	#[test]
+	fn wound_penalty_follows_adjusted_health() {
+		let more = adjusted(Target::Health, 2);
+		assert_eq!(max_health(&character(0), &more), 9);
+		assert_eq!(wound_penalty(&character(6), &more), 0);
+
+		let smaller = adjusted(Target::Size, -1);
+		assert_eq!(max_health(&character(0), &smaller), 6);
+		assert_eq!(wound_penalty(&character(5), &smaller), 2);
+	}
+
+	#[test]
+	fn adjustments_apply_to_their_trait() {
+		let character = character(0);
+		let extras = adjusted(Target::Speed, 3);
+
+		assert_eq!(speed(&character, &extras), character.speed() + 3);
+		assert_eq!(defense(&character, &extras), character.defense());
+		assert_eq!(
+			parts(&character, &extras, Target::Speed),
+			vec![(fl!("adjustment"), 3)]
+		);
+	}
+
+	#[test]
+	fn halving_comes_after_armor() {
+		let character = Character::builder()
+			.with_attributes(Attributes {
+				wits: 3,
+				dexterity: 3,
+				..Default::default()
+			})
+			.with_skills(Skills {
+				athletics: 2,
+				..Default::default()
+			})
+			.build();
+		let mut extras = CharacterExtras {
+			armor: vec![Armor {
+				defense: 1,
+				equipped: true,
+				..Default::default()
+			}],
+			..Default::default()
+		};
+		assert_eq!(defense(&character, &extras), 4);
+
+		extras.tilts.push(Tilt {
+			key: "tilt-stunned".to_string(),
+			turns: None,
+		});
+		assert_eq!(defense(&character, &extras), 2);
+
+		extras.adjustments.push(Adjustment {
+			name: String::new(),
+			target: Target::Defense,
+			value: 2,
+		});
+		assert_eq!(defense(&character, &extras), 3);
+	}
+}
@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+
+/// Free-form notes about the character, kept as plain text.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Notes {
+	pub background: String,
+	pub description: String,
+	pub goals: String,
+	/// Session journal, oldest first.
+	pub entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Entry {
+	pub date: String,
+	pub title: String,
+	pub text: String,
+}
+
+impl Entry {
+	pub fn new() -> Self {
+		Self {
+			date: today(),
+			..Default::default()
+		}
+	}
+
+	fn matches(&self, query: &str) -> bool {
+		[&self.date, &self.title, &self.text]
+			.into_iter()
+			.any(|field| field.to_lowercase().contains(query))
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+	Background,
+	Description,
+	Goals,
+}
+
+impl Section {
+	pub fn all() -> [Section; 3] {
+		[Self::Background, Self::Description, Self::Goals]
+	}
+}
+
+impl Notes {
+	pub fn section(&self, section: Section) -> &String {
+		match section {
+			Section::Background => &self.background,
+			Section::Description => &self.description,
+			Section::Goals => &self.goals,
+		}
+	}
+
+	pub fn section_mut(&mut self, section: Section) -> &mut String {
+		match section {
+			Section::Background => &mut self.background,
+			Section::Description => &mut self.description,
+			Section::Goals => &mut self.goals,
+		}
+	}
+
+	/// Journal entries matching `query`, newest first. Matching ignores case.
+	pub fn search(&self, query: &str) -> Vec<usize> {
+		let query = query.trim().to_lowercase();
+
+		(0..self.entries.len())
+			.rev()
+			.filter(|i| query.is_empty() || self.entries[*i].matches(&query))
+			.collect()
+	}
+}
+
+/// Today's date as `YYYY-MM-DD`. The browser build has no clock to ask, so it's left blank there.
+#[cfg(not(target_arch = "wasm32"))]
+fn today() -> String {
+	let days = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map_or(0, |duration| duration.as_secs() / 86_400);
+
+	date(days)
+}
+
+/// Days since 1970-01-01 to a civil date, from Howard Hinnant's `civil_from_days`.
+#[cfg(not(target_arch = "wasm32"))]
+fn date(days: u64) -> String {
+	let z = i64::try_from(days).unwrap_or(0) + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z.rem_euclid(146_097);
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + i64::from(month <= 2);
+
+	format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(target_arch = "wasm32")]
+fn today() -> String {
+	String::new()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn entry(date: &str, title: &str, text: &str) -> Entry {
+		Entry {
+			date: date.to_string(),
+			title: title.to_string(),
+			text: text.to_string(),
+		}
+	}
+
+	fn notes() -> Notes {
+		Notes {
+			entries: vec![
+				entry("2024-01-05", "The Dragolescu estate", "Met the Ventrue."),
+				entry("2024-01-12", "Night trains", "Lost the ledger."),
+				entry("2024-02-02", "", "The ledger turns up in the estate."),
+			],
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn search_lists_newest_first() {
+		assert_eq!(notes().search(""), vec![2, 1, 0]);
+		assert_eq!(notes().search("   "), vec![2, 1, 0]);
+	}
+
+	#[test]
+	fn search_matches_any_field_ignoring_case() {
+		let notes = notes();
+		assert_eq!(notes.search("LEDGER"), vec![2, 1]);
+		assert_eq!(notes.search("estate"), vec![2, 0]);
+		assert_eq!(notes.search("2024-01"), vec![1, 0]);
+		assert_eq!(notes.search(" trains "), vec![1]);
+		assert!(notes.search("werewolf").is_empty());
+	}
+
+	#[test]
+	fn dates_from_days() {
+		assert_eq!(date(0), "1970-01-01");
+		assert_eq!(date(59), "1970-03-01");
+		assert_eq!(date(11_016), "2000-02-29");
+		assert_eq!(date(19_723), "2024-01-01");
+	}
+
+	#[test]
+	fn today_is_a_date() {
+		let today = today();
+		let parts: Vec<&str> = today.split('-').collect();
+
+		assert_eq!(parts.len(), 3);
+		assert_eq!([parts[0].len(), parts[1].len(), parts[2].len()], [4, 2, 2]);
+		assert!(parts.iter().all(|part| part.parse::<u16>().is_ok()));
+		assert!(today.as_str() >= "2024-01-01");
+	}
+}
@@ -0,0 +1,146 @@
+use cofd::{prelude::*, splat::Merit};
+use serde::{Deserialize, Serialize};
+
+use crate::extras::CharacterExtras;
+
+/// A temporary drop in Resources after buying something at the limit of the character's means.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Reduction {
+	pub item: String,
+	pub amount: u16,
+	/// Chapters until the money is back.
+	pub chapters: u16,
+}
+
+/// What it takes to buy something, by its Availability against effective Resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+	/// At or below the character's Resources: just buy it.
+	Free,
+	/// Above the character's Resources: it takes a successful roll or a reduction.
+	Above,
+}
+
+/// Dots in the Resources Merit.
+pub fn rating(character: &Character) -> u16 {
+	character
+		.merits
+		.iter()
+		.filter(|(merit, _)| *merit == Merit::Resources)
+		.map(|(_, val)| *val)
+		.max()
+		.unwrap_or(0)
+}
+
+pub fn reduction(extras: &CharacterExtras) -> u16 {
+	extras
+		.reductions
+		.iter()
+		.map(|reduction| reduction.amount)
+		.sum()
+}
+
+/// Resources after any reductions from recent purchases.
+pub fn effective(character: &Character, extras: &CharacterExtras) -> u16 {
+	rating(character).saturating_sub(reduction(extras))
+}
+
+pub fn check(availability: u16, resources: u16) -> Check {
+	if availability <= resources {
+		Check::Free
+	} else {
+		Check::Above
+	}
+}
+
+/// The penalty to the Wits + Streetwise roll for something above the character's means.
+pub fn shortfall(availability: u16, resources: u16) -> i32 {
+	(i32::from(availability) - i32::from(resources)).max(0)
+}
+
+/// Whether the character has any Resources left to reduce.
+pub fn can_reduce(character: &Character, extras: &CharacterExtras) -> bool {
+	effective(character, extras) > 0
+}
+
+/// Pays for something above the character's means by giving up a dot of Resources until the end
+/// of the chapter.
+pub fn reduce(extras: &mut CharacterExtras, item: String) {
+	extras.reductions.push(Reduction {
+		item,
+		amount: 1,
+		chapters: 1,
+	});
+}
+
+/// Counts down reductions at the end of a chapter, restoring the ones that ran out.
+pub fn end_chapter(extras: &mut CharacterExtras) {
+	for reduction in &mut extras.reductions {
+		reduction.chapters = reduction.chapters.saturating_sub(1);
+	}
+
+	extras.reductions.retain(|reduction| reduction.chapters > 0);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn character(resources: u16) -> Character {
+		Character::builder()
+			.with_merits([(Merit::Resources, resources)])
+			.build()
+	}
+
+	#[test]
+	fn free_up_to_the_rating() {
+		assert_eq!(check(0, 0), Check::Free);
+		assert_eq!(check(2, 3), Check::Free);
+		assert_eq!(check(3, 3), Check::Free);
+		assert_eq!(check(4, 3), Check::Above);
+	}
+
+	#[test]
+	fn shortfall_is_the_gap_above_resources() {
+		assert_eq!(shortfall(5, 3), 2);
+		assert_eq!(shortfall(3, 3), 0);
+		assert_eq!(shortfall(1, 3), 0);
+	}
+
+	#[test]
+	fn reductions_lower_effective_resources() {
+		let character = character(3);
+		let mut extras = CharacterExtras::default();
+		assert_eq!(rating(&character), 3);
+		assert_eq!(effective(&character, &extras), 3);
+
+		reduce(&mut extras, "Car".to_string());
+		reduce(&mut extras, "Laptop".to_string());
+		assert_eq!(effective(&character, &extras), 1);
+		assert!(can_reduce(&character, &extras));
+
+		reduce(&mut extras, "Boat".to_string());
+		reduce(&mut extras, "Plane".to_string());
+		assert_eq!(effective(&character, &extras), 0);
+		assert!(!can_reduce(&character, &extras));
+	}
+
+	#[test]
+	fn reductions_run_out_at_the_end_of_a_chapter() {
+		let mut extras = CharacterExtras::default();
+		reduce(&mut extras, "Car".to_string());
+		extras.reductions.push(Reduction {
+			item: "House".to_string(),
+			amount: 2,
+			chapters: 2,
+		});
+
+		end_chapter(&mut extras);
+		assert_eq!(extras.reductions.len(), 1);
+		assert_eq!(reduction(&extras), 2);
+
+		end_chapter(&mut extras);
+		assert!(extras.reductions.is_empty());
+	}
+}
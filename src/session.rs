@@ -0,0 +1,216 @@
+use cofd::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	aspirations, extras::CharacterExtras, fuel::FuelUse, health, history::Event, i18n,
+	i18n::Translate, modifiers, resources, willpower,
+};
+
+/// What the character has already done this scene or chapter, for once-per-scene and
+/// once-per-chapter rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+	/// Willpower regained through the Vice this scene.
+	pub vice: bool,
+	/// Willpower regained through the Virtue this chapter.
+	pub virtue: bool,
+	pub fuel: FuelUse,
+}
+
+impl Session {
+	pub fn end_scene(&mut self) {
+		self.vice = false;
+		self.fuel = FuelUse::default();
+	}
+
+	pub fn end_chapter(&mut self) {
+		self.end_scene();
+		self.virtue = false;
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum End {
+	Scene,
+	/// The end of a game night; the characters get some rest before the next one.
+	Chapter,
+	Story,
+}
+
+impl Translate for End {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(match self {
+			Self::Scene => "scene",
+			Self::Chapter => "chapter",
+			Self::Story => "story",
+		})
+	}
+}
+
+/// Beats claimed when something ends.
+#[derive(Debug, Clone, Default)]
+pub struct Awards {
+	/// Indices of Aspirations fulfilled.
+	pub aspirations: Vec<usize>,
+	/// Indices of Conditions resolved.
+	pub conditions: Vec<usize>,
+	pub dramatic_failures: u16,
+}
+
+/// Wraps up a scene, chapter or story: hands out Beats, resets what's limited to it, and
+/// lets the character recover.
+pub fn end(character: &mut Character, extras: &mut CharacterExtras, end: End, awards: &Awards) {
+	let mut beats = 0;
+
+	// Fulfilling an Aspiration hands out its own Beats.
+	let mut fulfilled = 0;
+	for i in &awards.aspirations {
+		fulfilled += aspirations::fulfill(character, extras, *i);
+	}
+
+	let mut conditions = awards.conditions.clone();
+	conditions.sort_unstable();
+	conditions.dedup();
+	for i in conditions.into_iter().rev() {
+		if i < character.conditions.len() {
+			character.conditions.remove(i);
+			beats += 1;
+		}
+	}
+
+	beats += awards.dramatic_failures;
+	character.beats += beats;
+	aspirations::sync(character, extras);
+
+	// Tilts don't outlast the scene.
+	extras.tilts.clear();
+
+	let mut healed = 0;
+	let mut willpower = 0;
+
+	if end == End::Scene {
+		extras.session.end_scene();
+	} else {
+		extras.session.end_chapter();
+		resources::end_chapter(extras);
+
+		if health::heals_naturally(character) {
+			let max = modifiers::max_health(character, extras) as usize;
+			let before = health::damaged(character.health(), max);
+
+			extras
+				.recovery
+				.rest(character.health_mut(), max, health::NIGHT);
+			healed = before - health::damaged(character.health(), max);
+		}
+
+		willpower = if end == End::Story {
+			willpower::recover(
+				character,
+				extras,
+				modifiers::max_willpower(character, extras),
+			)
+		} else {
+			willpower::recover(character, extras, 1)
+		};
+	}
+
+	extras.history.push(Event::Ended {
+		end,
+		beats: beats + fulfilled,
+		healed: u16::try_from(healed).unwrap_or(u16::MAX),
+		willpower,
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		aspirations::{Aspiration, Term},
+		tilts::Tilt,
+	};
+
+	fn character() -> Character {
+		let mut character = Character::builder()
+			.with_attributes(Attributes {
+				resolve: 2,
+				composure: 3,
+				..Default::default()
+			})
+			.build();
+		character.willpower = 1;
+		character
+	}
+
+	#[test]
+	fn awards_each_beat_once() {
+		let mut character = character();
+		character.conditions = vec!["condition-shaken".to_string(), "Lost".to_string()];
+		let mut extras = CharacterExtras {
+			aspirations: vec![
+				Aspiration::new("Find the book".to_string()),
+				Aspiration {
+					term: Term::Long,
+					..Aspiration::new("Leave the city".to_string())
+				},
+			],
+			..Default::default()
+		};
+
+		let awards = Awards {
+			aspirations: vec![0, 1],
+			conditions: vec![1, 1],
+			dramatic_failures: 2,
+		};
+		end(&mut character, &mut extras, End::Scene, &awards);
+
+		assert_eq!(character.beats, 1 + 2 + 1 + 2);
+		assert_eq!(character.conditions, vec!["condition-shaken".to_string()]);
+		assert!(extras
+			.aspirations
+			.iter()
+			.all(|aspiration| !aspiration.is_active()));
+		assert!(matches!(
+			extras.history.last(),
+			Some(Event::Ended { beats: 6, .. })
+		));
+	}
+
+	#[test]
+	fn scene_resets_what_lasts_a_scene() {
+		let mut character = character();
+		let mut extras = CharacterExtras::default();
+		extras.tilts.push(Tilt::new("tilt-stunned".to_string(), 1));
+		extras.session.vice = true;
+		extras.session.virtue = true;
+
+		end(&mut character, &mut extras, End::Scene, &Awards::default());
+
+		assert!(extras.tilts.is_empty());
+		assert!(!extras.session.vice);
+		assert!(extras.session.virtue);
+		assert_eq!(character.willpower, 1);
+		assert_eq!(character.beats, 0);
+	}
+
+	#[test]
+	fn chapter_and_story_recover_willpower() {
+		let mut character = character();
+		let mut extras = CharacterExtras::default();
+		extras.session.virtue = true;
+
+		end(
+			&mut character,
+			&mut extras,
+			End::Chapter,
+			&Awards::default(),
+		);
+		assert!(!extras.session.virtue);
+		assert_eq!(character.willpower, 2);
+
+		end(&mut character, &mut extras, End::Story, &Awards::default());
+		assert_eq!(character.willpower, 5);
+	}
+}
@@ -0,0 +1,480 @@
+use cofd::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	extras::CharacterExtras,
+	i18n::{self, Translate},
+	training,
+};
+
+/// Where a Specialty came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Source {
+	#[default]
+	Creation,
+	Experience,
+	Merit,
+	/// One of Professional Training's free Specialties in an Asset Skill.
+	Training,
+}
+
+impl Source {
+	pub fn all() -> [Source; 4] {
+		[
+			Self::Creation,
+			Self::Experience,
+			Self::Merit,
+			Self::Training,
+		]
+	}
+}
+
+impl Translate for Source {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(match self {
+			Self::Creation => "specialty-creation",
+			Self::Experience => "specialty-experience",
+			Self::Merit => "specialty-merit",
+			Self::Training => "professional-training",
+		})
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Specialty {
+	pub skill: Skill,
+	pub name: String,
+	pub source: Source,
+	/// Whether the Specialty counts. A free one Professional Training no longer grants, such as
+	/// after its Asset Skills change, is kept until removed.
+	pub active: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+	Empty,
+	Duplicate,
+	/// Professional Training has no free Specialty left for the Skill.
+	Training,
+}
+
+/// Specialties are the same if they only differ in case or surrounding space.
+pub fn same(a: &str, b: &str) -> bool {
+	a.trim().to_lowercase() == b.trim().to_lowercase()
+}
+
+fn source(extras: &CharacterExtras, skill: Skill, name: &str) -> Source {
+	extras
+		.specialty_sources
+		.iter()
+		.find(|(s, n, _)| *s == skill && n == name)
+		.map_or(Source::default(), |(_, _, source)| *source)
+}
+
+/// Every Specialty the character has, those on the sheet and the free ones, by Skill.
+pub fn all(character: &Character, extras: &CharacterExtras) -> Vec<Specialty> {
+	let mut vec = Vec::new();
+
+	for skill in Skill::all() {
+		if let Some(names) = character.specialties.get(&skill) {
+			vec.extend(names.iter().map(|name| Specialty {
+				skill,
+				name: name.clone(),
+				source: source(extras, skill, name),
+				active: true,
+			}));
+		}
+
+		vec.extend(
+			extras
+				.specialties
+				.iter()
+				.filter(|(s, _)| *s == skill)
+				.map(|(_, name)| Specialty {
+					skill,
+					name: name.clone(),
+					source: Source::Training,
+					active: training::grants_specialty(character, skill),
+				}),
+		);
+	}
+
+	vec
+}
+
+/// The Specialties that count for the Skill.
+pub fn of(character: &Character, extras: &CharacterExtras, skill: Skill) -> Vec<Specialty> {
+	all(character, extras)
+		.into_iter()
+		.filter(|specialty| specialty.skill == skill && specialty.active)
+		.collect()
+}
+
+/// Whether another Specialty in the list has the same Skill and name.
+pub fn is_duplicate(specialties: &[Specialty], specialty: &Specialty) -> bool {
+	specialties
+		.iter()
+		.filter(|other| other.skill == specialty.skill && same(&other.name, &specialty.name))
+		.count()
+		> 1
+}
+
+/// Common Specialties for the Skill, from the `.specialties` attribute of its message.
+pub fn suggestions(skill: Skill) -> Vec<String> {
+	i18n::attr(skill.name(), "specialties")
+		.map(|list| {
+			list.split(',')
+				.map(str::trim)
+				.filter(|name| !name.is_empty())
+				.map(String::from)
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
+pub fn add(
+	character: &mut Character,
+	extras: &mut CharacterExtras,
+	skill: Skill,
+	name: &str,
+	source: Source,
+) -> Result<(), Error> {
+	let name = name.trim();
+	if name.is_empty() {
+		return Err(Error::Empty);
+	}
+
+	if all(character, extras)
+		.iter()
+		.any(|specialty| specialty.skill == skill && same(&specialty.name, name))
+	{
+		return Err(Error::Duplicate);
+	}
+
+	if source == Source::Training {
+		// Ones that no longer apply still take up room until they're removed.
+		let room = extras.specialties.len() < training::FREE_SPECIALTIES;
+		if !(room && training::grants_specialty(character, skill)) {
+			return Err(Error::Training);
+		}
+
+		extras.specialties.push((skill, name.to_string()));
+	} else {
+		character
+			.specialties
+			.entry(skill)
+			.or_default()
+			.push(name.to_string());
+
+		if source != Source::default() {
+			extras
+				.specialty_sources
+				.push((skill, name.to_string(), source));
+		}
+	}
+
+	Ok(())
+}
+
+pub fn remove(character: &mut Character, extras: &mut CharacterExtras, specialty: &Specialty) {
+	let Specialty {
+		skill,
+		name,
+		source,
+		..
+	} = specialty;
+
+	if *source == Source::Training {
+		if let Some(pos) = extras
+			.specialties
+			.iter()
+			.position(|(s, n)| s == skill && n == name)
+		{
+			extras.specialties.remove(pos);
+		}
+		return;
+	}
+
+	if let Some(vec) = character.specialties.get_mut(skill) {
+		if let Some(pos) = vec.iter().position(|n| n == name) {
+			vec.remove(pos);
+		}
+		if vec.is_empty() {
+			character.specialties.remove(skill);
+		}
+	}
+
+	extras
+		.specialty_sources
+		.retain(|(s, n, _)| !(s == skill && n == name));
+}
+
+#[cfg(test)]
+mod tests {
+	use cofd::splat::Merit;
+
+	use super::*;
+
+	fn trained(dots: u16) -> Character {
+		Character::builder()
+			.with_merits([(
+				Merit::ProfessionalTraining {
+					profession: "Doctor".to_string(),
+					skills: [Skill::Medicine, Skill::Science],
+					skill: None,
+				},
+				dots,
+			)])
+			.build()
+	}
+
+	fn names(character: &Character, extras: &CharacterExtras) -> Vec<(Skill, String, Source)> {
+		all(character, extras)
+			.into_iter()
+			.map(|specialty| (specialty.skill, specialty.name, specialty.source))
+			.collect()
+	}
+
+	#[test]
+	fn add_keeps_the_source() {
+		let mut character = Character::default();
+		let mut extras = CharacterExtras::default();
+
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Brawl,
+				" Boxing ",
+				Source::Creation
+			),
+			Ok(())
+		);
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Brawl,
+				"Grappling",
+				Source::Experience
+			),
+			Ok(())
+		);
+
+		assert_eq!(
+			character.specialties.get(&Skill::Brawl),
+			Some(&vec!["Boxing".to_string(), "Grappling".to_string()])
+		);
+		assert_eq!(
+			names(&character, &extras),
+			vec![
+				(Skill::Brawl, "Boxing".to_string(), Source::Creation),
+				(Skill::Brawl, "Grappling".to_string(), Source::Experience),
+			]
+		);
+	}
+
+	#[test]
+	fn add_rejects_empty_and_duplicate_names() {
+		let mut character = Character::default();
+		let mut extras = CharacterExtras::default();
+		add(
+			&mut character,
+			&mut extras,
+			Skill::Brawl,
+			"Boxing",
+			Source::Creation,
+		)
+		.unwrap();
+
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Brawl,
+				"  ",
+				Source::Creation
+			),
+			Err(Error::Empty)
+		);
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Brawl,
+				"boxing ",
+				Source::Merit
+			),
+			Err(Error::Duplicate)
+		);
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Athletics,
+				"Boxing",
+				Source::Creation
+			),
+			Ok(())
+		);
+	}
+
+	#[test]
+	fn training_specialties_need_the_merit_and_an_asset_skill() {
+		let mut extras = CharacterExtras::default();
+
+		let mut character = trained(1);
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Medicine,
+				"Surgery",
+				Source::Training
+			),
+			Err(Error::Training)
+		);
+
+		let mut character = trained(2);
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Brawl,
+				"Boxing",
+				Source::Training
+			),
+			Err(Error::Training)
+		);
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Medicine,
+				"Surgery",
+				Source::Training
+			),
+			Ok(())
+		);
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Science,
+				"Biology",
+				Source::Training
+			),
+			Ok(())
+		);
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Science,
+				"Chemistry",
+				Source::Training
+			),
+			Err(Error::Training)
+		);
+
+		assert!(character.specialties.is_empty());
+		assert_eq!(
+			names(&character, &extras),
+			vec![
+				(Skill::Medicine, "Surgery".to_string(), Source::Training),
+				(Skill::Science, "Biology".to_string(), Source::Training),
+			]
+		);
+	}
+
+	#[test]
+	fn training_specialties_outside_the_asset_skills_stay_until_removed() {
+		let mut character = trained(2);
+		let mut extras = CharacterExtras::default();
+		add(
+			&mut character,
+			&mut extras,
+			Skill::Medicine,
+			"Surgery",
+			Source::Training,
+		)
+		.unwrap();
+
+		character.merits[0].0 = Merit::ProfessionalTraining {
+			profession: "Doctor".to_string(),
+			skills: [Skill::Science, Skill::Occult],
+			skill: None,
+		};
+
+		let surgery = all(&character, &extras).remove(0);
+		assert_eq!(surgery.name, "Surgery");
+		assert!(!surgery.active);
+		assert!(of(&character, &extras, Skill::Medicine).is_empty());
+
+		// It still takes up one of the two free Specialties.
+		add(
+			&mut character,
+			&mut extras,
+			Skill::Science,
+			"Biology",
+			Source::Training,
+		)
+		.unwrap();
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Occult,
+				"Ghosts",
+				Source::Training
+			),
+			Err(Error::Training)
+		);
+
+		remove(&mut character, &mut extras, &surgery);
+		assert_eq!(
+			add(
+				&mut character,
+				&mut extras,
+				Skill::Occult,
+				"Ghosts",
+				Source::Training
+			),
+			Ok(())
+		);
+		assert!(all(&character, &extras)
+			.iter()
+			.all(|specialty| specialty.active));
+	}
+
+	#[test]
+	fn remove_clears_the_specialty_and_its_source() {
+		let mut character = trained(2);
+		let mut extras = CharacterExtras::default();
+		add(
+			&mut character,
+			&mut extras,
+			Skill::Brawl,
+			"Boxing",
+			Source::Experience,
+		)
+		.unwrap();
+		add(
+			&mut character,
+			&mut extras,
+			Skill::Medicine,
+			"Surgery",
+			Source::Training,
+		)
+		.unwrap();
+
+		for specialty in all(&character, &extras) {
+			remove(&mut character, &mut extras, &specialty);
+		}
+
+		assert!(all(&character, &extras).is_empty());
+		assert!(character.specialties.is_empty());
+		assert!(extras.specialties.is_empty());
+		assert!(extras.specialty_sources.is_empty());
+	}
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{catalog::tilts, i18n::Translate};
+
+/// A Tilt affecting a character, keyed into the Tilt catalog.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Tilt {
+	pub key: String,
+	/// Turns left, or `None` if it lasts until removed.
+	pub turns: Option<u16>,
+}
+
+impl Tilt {
+	pub fn new(key: String, turns: u16) -> Self {
+		Self {
+			key,
+			turns: (turns > 0).then_some(turns),
+		}
+	}
+
+	pub fn name(&self) -> String {
+		tilts::get(&self.key).map_or_else(|| self.key.clone(), Translate::translated)
+	}
+}
+
+/// Counts down every timed Tilt by a turn, dropping the ones that ran out.
+pub fn tick(tilts: &mut Vec<Tilt>) {
+	for tilt in tilts.iter_mut() {
+		if let Some(turns) = &mut tilt.turns {
+			*turns = turns.saturating_sub(1);
+		}
+	}
+
+	tilts.retain(|tilt| tilt.turns != Some(0));
+}
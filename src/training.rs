@@ -0,0 +1,146 @@
+use cofd::{prelude::*, splat::Merit};
+
+use crate::dice::Again;
+
+/// Dots of Professional Training that grant free Specialties in the Asset Skills.
+const SPECIALTIES: u16 = 2;
+/// Dots that grant 9-again on Asset Skill rolls.
+const NINE_AGAIN: u16 = 3;
+/// Dots that grant a free dot in a Skill of the character's choice.
+const SKILL_DOT: u16 = 4;
+
+/// How many Specialties Professional Training grants.
+pub const FREE_SPECIALTIES: usize = 2;
+
+/// The character's Professional Training, with the most dots if they have more than one.
+fn training(character: &Character) -> Option<(&[Skill; 2], Option<Skill>, u16)> {
+	character
+		.merits
+		.iter()
+		.filter_map(|(merit, val)| match merit {
+			Merit::ProfessionalTraining { skills, skill, .. } => Some((skills, *skill, *val)),
+			_ => None,
+		})
+		.max_by_key(|(_, _, val)| *val)
+}
+
+pub fn is_asset(character: &Character, skill: Skill) -> bool {
+	training(character).is_some_and(|(skills, _, _)| skills.contains(&skill))
+}
+
+/// Whether the character gets free Specialties in the Asset Skills.
+pub fn grants_specialties(character: &Character) -> bool {
+	training(character).is_some_and(|(_, _, val)| val >= SPECIALTIES)
+}
+
+/// Whether a free Specialty in the Skill applies: the Merit has the dots and it's an Asset Skill.
+pub fn grants_specialty(character: &Character, skill: Skill) -> bool {
+	grants_specialties(character) && is_asset(character, skill)
+}
+
+/// The free Skill dot, if the Merit is rated high enough and one was picked.
+pub fn skill_dot(character: &Character, skill: Skill) -> u16 {
+	training(character).map_or(0, |(_, picked, val)| {
+		u16::from(val >= SKILL_DOT && picked == Some(skill))
+	})
+}
+
+/// Rolls using an Asset Skill get 9-again, unless they already reroll more.
+pub fn again(character: &Character, skills: &[Skill], again: Again) -> Again {
+	let applies = training(character).is_some_and(|(assets, _, val)| {
+		val >= NINE_AGAIN && skills.iter().any(|skill| assets.contains(skill))
+	});
+
+	if applies && again == Again::Ten {
+		Again::Nine
+	} else {
+		again
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn merit(skills: [Skill; 2], skill: Option<Skill>, dots: u16) -> (Merit, u16) {
+		(
+			Merit::ProfessionalTraining {
+				profession: "Doctor".to_string(),
+				skills,
+				skill,
+			},
+			dots,
+		)
+	}
+
+	fn trained(dots: u16) -> Character {
+		Character::builder()
+			.with_merits([merit(
+				[Skill::Medicine, Skill::Science],
+				Some(Skill::Occult),
+				dots,
+			)])
+			.build()
+	}
+
+	#[test]
+	fn benefits_come_with_the_dots() {
+		let one = trained(1);
+		assert!(!grants_specialties(&one));
+		assert_eq!(again(&one, &[Skill::Medicine], Again::Ten), Again::Ten);
+
+		let two = trained(2);
+		assert!(grants_specialties(&two));
+		assert!(grants_specialty(&two, Skill::Science));
+		assert!(!grants_specialty(&two, Skill::Occult));
+		assert_eq!(again(&two, &[Skill::Medicine], Again::Ten), Again::Ten);
+
+		let three = trained(3);
+		assert_eq!(again(&three, &[Skill::Medicine], Again::Ten), Again::Nine);
+		assert_eq!(skill_dot(&three, Skill::Occult), 0);
+
+		let four = trained(4);
+		assert_eq!(skill_dot(&four, Skill::Occult), 1);
+		assert_eq!(skill_dot(&four, Skill::Medicine), 0);
+	}
+
+	#[test]
+	fn nine_again_needs_an_asset_skill() {
+		let character = trained(3);
+		assert_eq!(again(&character, &[Skill::Brawl], Again::Ten), Again::Ten);
+		assert_eq!(
+			again(&character, &[Skill::Brawl, Skill::Science], Again::Ten),
+			Again::Nine
+		);
+		assert_eq!(again(&character, &[], Again::Ten), Again::Ten);
+	}
+
+	#[test]
+	fn nine_again_only_replaces_ten_again() {
+		let character = trained(3);
+		for other in [Again::Nine, Again::Eight, Again::No] {
+			assert_eq!(again(&character, &[Skill::Medicine], other), other);
+		}
+	}
+
+	#[test]
+	fn the_highest_rated_merit_counts() {
+		let character = Character::builder()
+			.with_merits([
+				merit([Skill::Brawl, Skill::Weaponry], None, 1),
+				merit([Skill::Medicine, Skill::Science], Some(Skill::Occult), 4),
+				merit([Skill::Crafts, Skill::Expression], Some(Skill::Drive), 2),
+			])
+			.build();
+
+		assert!(is_asset(&character, Skill::Medicine));
+		assert!(!is_asset(&character, Skill::Brawl));
+		assert!(!is_asset(&character, Skill::Crafts));
+		assert_eq!(skill_dot(&character, Skill::Occult), 1);
+		assert_eq!(skill_dot(&character, Skill::Drive), 0);
+		assert_eq!(
+			again(&character, &[Skill::Weaponry], Again::Ten),
+			Again::Ten
+		);
+	}
+}
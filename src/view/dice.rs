@@ -0,0 +1,579 @@
+use std::fmt::{self, Display};
+
+use cofd::prelude::*;
+use iced::{
+	widget::{button, checkbox, pick_list, row, text, text_input, Column, Row},
+	Alignment, Length,
+};
+
+use crate::{
+	catalog::styles::{self, Maneuver},
+	dice::{expr, pool, Again, Opposition, Outcome, Pool, PoolSpec, PoolTrait, Request},
+	extras::CharacterExtras,
+	fl, i18n,
+	i18n::{Translate, Translated},
+	modifiers::{self, Target},
+	specialties, training,
+	willpower::{self, Spend},
+	Element, H2_SIZE, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
+};
+
+const HISTORY_LEN: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+	#[default]
+	Simple,
+	Resisted,
+	Contested,
+}
+
+impl Mode {
+	pub fn all() -> [Mode; 3] {
+		[Self::Simple, Self::Resisted, Self::Contested]
+	}
+}
+
+impl Translate for Mode {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(match self {
+			Self::Simple => "roll-simple",
+			Self::Resisted => "roll-resisted",
+			Self::Contested => "roll-contested",
+		})
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Opponent {
+	Character(usize, String),
+	Manual,
+}
+
+impl Display for Opponent {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Character(_, name) => f.write_str(name),
+			Self::Manual => f.write_str(&fl!("manual")),
+		}
+	}
+}
+
+/// A Specialty to add its die to the pool, or none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpecialtyPick {
+	None,
+	Specialty(String),
+}
+
+impl Display for SpecialtyPick {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::None => f.write_str(&fl!("no-specialty")),
+			Self::Specialty(name) => f.write_str(name),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct DiceTab {
+	pool: PoolSpec,
+	specialty: Option<String>,
+	again: Again,
+	mode: Mode,
+
+	opponent: Option<Opponent>,
+	resistance: Attribute,
+	supernatural: bool,
+	opponent_pool: PoolSpec,
+	manual: i32,
+
+	history: Vec<Outcome>,
+}
+
+#[derive(Clone)]
+pub enum Message {
+	Attribute(Attribute),
+	Other(PoolTrait),
+	Specialty(SpecialtyPick),
+	Modifier(i32),
+	Again(Again),
+	ResetPool,
+
+	Mode(Mode),
+	Opponent(Opponent),
+	Resistance(Attribute),
+	Supernatural(bool),
+	OpponentAttribute(Attribute),
+	OpponentOther(PoolTrait),
+	Manual(i32),
+
+	Roll(Request),
+	ClearHistory,
+}
+
+pub fn character_name(character: &Character) -> String {
+	if character.info.name.is_empty() {
+		fl!("unnamed")
+	} else {
+		character.info.name.clone()
+	}
+}
+
+impl DiceTab {
+	pub fn new() -> Self {
+		Self {
+			pool: PoolSpec::default(),
+			specialty: None,
+			again: Again::default(),
+			mode: Mode::default(),
+			opponent: None,
+			resistance: Attribute::Resolve,
+			supernatural: true,
+			opponent_pool: PoolSpec {
+				attribute: Some(Attribute::Resolve),
+				..Default::default()
+			},
+			manual: 0,
+			history: Vec::new(),
+		}
+	}
+
+	pub fn update(&mut self, message: Message) {
+		match message {
+			Message::Attribute(attr) => self.pool.attribute = Some(attr),
+			Message::Other(other) => {
+				if self.pool.other != Some(other) {
+					self.specialty = None;
+				}
+				self.pool.other = Some(other);
+			}
+			Message::Specialty(pick) => {
+				self.specialty = match pick {
+					SpecialtyPick::None => None,
+					SpecialtyPick::Specialty(name) => Some(name),
+				};
+			}
+			Message::Modifier(modifier) => self.pool.modifier = modifier,
+			Message::Again(again) => self.again = again,
+			Message::ResetPool => {
+				self.pool = PoolSpec::default();
+				self.specialty = None;
+				self.again = Again::default();
+			}
+
+			Message::Mode(mode) => self.mode = mode,
+			Message::Opponent(opponent) => self.opponent = Some(opponent),
+			Message::Resistance(attr) => self.resistance = attr,
+			Message::Supernatural(supernatural) => self.supernatural = supernatural,
+			Message::OpponentAttribute(attr) => self.opponent_pool.attribute = Some(attr),
+			Message::OpponentOther(other) => self.opponent_pool.other = Some(other),
+			Message::Manual(manual) => self.manual = manual,
+
+			Message::Roll(request) => self.push(request.roll()),
+			Message::ClearHistory => self.history.clear(),
+		}
+	}
+
+	/// The roster character a roll is up against, and the Willpower spend of theirs it uses up.
+	pub fn opponent(&self, request: &Request) -> Option<(usize, Spend)> {
+		let spend = match request.opposition {
+			Opposition::None => return None,
+			Opposition::Resisted { .. } => Spend::Resistance,
+			Opposition::Contested { .. } => Spend::Dice,
+		};
+
+		match self.opponent {
+			Some(Opponent::Character(i, _)) => Some((i, spend)),
+			_ => None,
+		}
+	}
+
+	pub fn push(&mut self, outcome: Outcome) {
+		self.history.insert(0, outcome);
+		self.history.truncate(HISTORY_LEN);
+	}
+
+	/// Resolves what the player picked into concrete pools, ready to be rolled.
+	fn request(
+		&self,
+		character: &Character,
+		extras: &CharacterExtras,
+		roster: &[Character],
+		roster_extras: &[CharacterExtras],
+	) -> Request {
+		let opponent = match &self.opponent {
+			Some(Opponent::Character(i, name)) => roster
+				.get(*i)
+				.zip(roster_extras.get(*i))
+				.map(|c| (name.clone(), Some(c))),
+			Some(Opponent::Manual) => Some((fl!("manual"), None)),
+			None => None,
+		};
+
+		let opposition = match (self.mode, opponent) {
+			(Mode::Simple, _) | (_, None) => Opposition::None,
+			(Mode::Resisted, Some((name, opponent))) => Opposition::Resisted {
+				name,
+				resistance: if let Some((opponent, opponent_extras)) = opponent {
+					willpower::resistance(
+						Pool::resistance(opponent, self.resistance, self.supernatural),
+						opponent_extras,
+					)
+				} else {
+					Pool::new().bonus(fl!("resistance"), self.manual)
+				},
+			},
+			(Mode::Contested, Some((name, opponent))) => {
+				if let Some((opponent, opponent_extras)) = opponent {
+					let pool = self.opponent_pool.build(opponent, opponent_extras);
+					let skills: Vec<Skill> = match self.opponent_pool.other {
+						Some(PoolTrait::Skill(skill)) => vec![skill],
+						_ => Vec::new(),
+					};
+
+					Opposition::Contested {
+						name,
+						pool: if self.supernatural {
+							pool.supernatural_tolerance(opponent)
+						} else {
+							pool
+						},
+						again: training::again(opponent, &skills, Again::default()),
+					}
+				} else {
+					Opposition::Contested {
+						name,
+						pool: Pool::new().bonus(fl!("manual"), self.manual),
+						again: Again::default(),
+					}
+				}
+			}
+		};
+
+		let skills: Vec<Skill> = match self.pool.other {
+			Some(PoolTrait::Skill(skill)) => vec![skill],
+			_ => Vec::new(),
+		};
+
+		let mut pool = self.pool.build(character, extras);
+		if let Some(specialty) = self.specialty(character, extras) {
+			pool = pool.bonus(fl!("specialty-bonus", name = specialty), 1);
+		}
+
+		Request {
+			name: character_name(character),
+			pool,
+			again: training::again(character, &skills, self.again),
+			opposition,
+			damage: None,
+		}
+	}
+
+	/// The picked Specialty, as long as it's still one of the pool's Skill.
+	fn specialty(&self, character: &Character, extras: &CharacterExtras) -> Option<String> {
+		let Some(PoolTrait::Skill(skill)) = self.pool.other else {
+			return None;
+		};
+
+		self.specialty.clone().filter(|name| {
+			specialties::of(character, extras, skill)
+				.iter()
+				.any(|specialty| &specialty.name == name)
+		})
+	}
+
+	#[allow(clippy::too_many_lines)]
+	pub fn view<'a>(
+		&'a self,
+		character: &'a Character,
+		extras: &'a CharacterExtras,
+		roster: &'a [Character],
+		roster_extras: &'a [CharacterExtras],
+	) -> Element<'a, Message> {
+		let attributes: Vec<Translated<Attribute>> =
+			pool::attributes().into_iter().map(Into::into).collect();
+		let traits: Vec<Translated<PoolTrait>> =
+			PoolTrait::all().into_iter().map(Into::into).collect();
+
+		let pool = row![
+			text(format!("{}:", fl!("pool"))),
+			pick_list(
+				attributes.clone(),
+				self.pool.attribute.map(Into::<Translated<Attribute>>::into),
+				|val| Message::Attribute(val.unwrap())
+			)
+			.padding(INPUT_PADDING)
+			.width(Length::Fill),
+			text("+"),
+			pick_list(
+				traits.clone(),
+				self.pool.other.map(Into::<Translated<PoolTrait>>::into),
+				|val| Message::Other(val.unwrap())
+			)
+			.padding(INPUT_PADDING)
+			.width(Length::Fill),
+			text("+"),
+			text_input("", &self.pool.modifier.to_string())
+				.on_input(|val| Message::Modifier(val.parse().unwrap_or(0)))
+				.padding(INPUT_PADDING)
+				.width(Length::Fixed(40.0)),
+			button(text(fl!("reset")))
+				.padding(INPUT_PADDING)
+				.on_press(Message::ResetPool),
+		]
+		.spacing(5)
+		.align_items(Alignment::Center);
+
+		let again: Vec<Translated<Again>> = Again::all().into_iter().map(Into::into).collect();
+		let modes: Vec<Translated<Mode>> = Mode::all().into_iter().map(Into::into).collect();
+
+		let mut options = row![
+			pick_list(again, Some::<Translated<Again>>(self.again.into()), |val| {
+				Message::Again(val.unwrap())
+			})
+			.padding(INPUT_PADDING),
+			pick_list(modes, Some::<Translated<Mode>>(self.mode.into()), |val| {
+				Message::Mode(val.unwrap())
+			})
+			.padding(INPUT_PADDING),
+		]
+		.spacing(5);
+
+		if let Some(PoolTrait::Skill(skill)) = self.pool.other {
+			let mut picks = vec![SpecialtyPick::None];
+			picks.extend(
+				specialties::of(character, extras, skill)
+					.into_iter()
+					.map(|specialty| SpecialtyPick::Specialty(specialty.name)),
+			);
+
+			if picks.len() > 1 {
+				let selected = match self.specialty(character, extras) {
+					Some(name) => SpecialtyPick::Specialty(name),
+					None => SpecialtyPick::None,
+				};
+
+				options = options.push(
+					pick_list(picks, Some(selected), Message::Specialty).padding(INPUT_PADDING),
+				);
+			}
+		}
+
+		let mut col = Column::new()
+			.push(text(fl!("dice")).size(H2_SIZE))
+			.push(pool)
+			.push(options)
+			.spacing(TITLE_SPACING)
+			.align_items(Alignment::Center)
+			.max_width(600);
+
+		if self.mode != Mode::Simple {
+			let mut opponents: Vec<Opponent> = roster
+				.iter()
+				.enumerate()
+				.filter(|(_, c)| !std::ptr::eq(*c, character))
+				.map(|(i, c)| Opponent::Character(i, character_name(c)))
+				.collect();
+			opponents.push(Opponent::Manual);
+
+			let mut opposition = row![
+				text(format!("{}:", fl!("opponent"))),
+				pick_list(opponents, self.opponent.clone(), Message::Opponent)
+					.padding(INPUT_PADDING)
+					.width(Length::Fill),
+			]
+			.spacing(5)
+			.align_items(Alignment::Center);
+
+			match (&self.opponent, self.mode) {
+				(Some(Opponent::Manual), _) => {
+					opposition = opposition.push(
+						text_input("", &self.manual.to_string())
+							.on_input(|val| Message::Manual(val.parse().unwrap_or(0)))
+							.padding(INPUT_PADDING)
+							.width(Length::Fixed(40.0)),
+					);
+				}
+				(Some(Opponent::Character(..)), Mode::Resisted) => {
+					let resistances: Vec<Translated<Attribute>> =
+						[Attribute::Resolve, Attribute::Composure, Attribute::Stamina]
+							.into_iter()
+							.map(Into::into)
+							.collect();
+
+					opposition = opposition.push(
+						pick_list(
+							resistances,
+							Some::<Translated<Attribute>>(self.resistance.into()),
+							|val| Message::Resistance(val.unwrap()),
+						)
+						.padding(INPUT_PADDING),
+					);
+				}
+				(Some(Opponent::Character(..)), _) => {
+					opposition = opposition
+						.push(
+							pick_list(
+								attributes,
+								self.opponent_pool
+									.attribute
+									.map(Into::<Translated<Attribute>>::into),
+								|val| Message::OpponentAttribute(val.unwrap()),
+							)
+							.padding(INPUT_PADDING),
+						)
+						.push(text("+"))
+						.push(
+							pick_list(
+								traits,
+								self.opponent_pool
+									.other
+									.map(Into::<Translated<PoolTrait>>::into),
+								|val| Message::OpponentOther(val.unwrap()),
+							)
+							.padding(INPUT_PADDING),
+						);
+				}
+				(None, _) => {}
+			}
+
+			col = col.push(opposition);
+
+			if let Some(Opponent::Character(..)) = &self.opponent {
+				col = col.push(
+					checkbox(fl!("supernatural-power"), self.supernatural)
+						.on_toggle(Message::Supernatural),
+				);
+			}
+		}
+
+		let request = self.request(character, extras, roster, roster_extras);
+
+		col = col.push(text(request.pool.to_string()));
+		match &request.opposition {
+			Opposition::None => {}
+			Opposition::Resisted { name, resistance } => {
+				col = col.push(text(format!(
+					"{}: {resistance}",
+					fl!("resistance-of", name = name.clone())
+				)));
+			}
+			Opposition::Contested { name, pool, .. } => {
+				col = col.push(text(format!("{name}: {pool}")));
+			}
+		}
+
+		col = col.push(
+			row![
+				button(text(fl!("roll"))).on_press(Message::Roll(request)),
+				button(text(fl!("clear"))).on_press(Message::ClearHistory),
+			]
+			.spacing(5),
+		);
+
+		let maneuvers: Vec<Request> = styles::unlocked(character)
+			.into_iter()
+			.filter_map(|maneuver| Self::maneuver(maneuver, character, extras))
+			.collect();
+
+		if !maneuvers.is_empty() {
+			let mut row = Row::new().spacing(5);
+			for request in maneuvers {
+				row = row.push(
+					button(text(format!("{} ({})", request.name, request.pool.total())))
+						.padding(INPUT_PADDING)
+						.on_press(Message::Roll(request)),
+				);
+			}
+
+			col = col.push(text(fl!("maneuvers")).size(H3_SIZE)).push(row);
+		}
+
+		let mut history = Column::new().spacing(5).align_items(Alignment::Center);
+		for outcome in &self.history {
+			history = history.push(Self::outcome(outcome));
+		}
+
+		col.push(history).into()
+	}
+
+	/// Rolling a maneuver uses it, so its own bonuses count even if it isn't marked as in use.
+	fn maneuver(
+		maneuver: &Maneuver,
+		character: &Character,
+		extras: &CharacterExtras,
+	) -> Option<Request> {
+		let eval = expr::evaluate(maneuver.pool.as_ref()?, character, extras);
+		if !eval.is_valid() {
+			return None;
+		}
+
+		let again = training::again(character, &eval.skills, Again::default());
+		let mut pool = eval.pool;
+		if maneuver
+			.effects
+			.iter()
+			.any(|effect| effect.target == Target::Combat)
+		{
+			pool = modifiers::combat_pool(pool, character, extras);
+
+			if !maneuver.passive && !extras.maneuvers.contains(&maneuver.key) {
+				for effect in &maneuver.effects {
+					if effect.target == Target::Combat {
+						pool = pool.bonus(maneuver.translated(), i32::from(effect.value));
+					}
+				}
+			}
+		}
+
+		Some(Request {
+			name: maneuver.translated(),
+			pool,
+			again,
+			opposition: Opposition::None,
+			damage: None,
+		})
+	}
+
+	fn outcome(outcome: &Outcome) -> Element<Message> {
+		let mut col = Column::new().align_items(Alignment::Center);
+
+		match outcome {
+			Outcome::Simple {
+				name, pool, roll, ..
+			}
+			| Outcome::Resisted {
+				name, pool, roll, ..
+			} => {
+				col = col
+					.push(text(name).size(H3_SIZE))
+					.push(text(pool.to_string()))
+					.push(text(roll.to_string()));
+			}
+			Outcome::Contested {
+				name,
+				pool,
+				opponent,
+				opponent_pool,
+				contest,
+			} => {
+				col = col
+					.push(text(format!("{name} / {opponent}")).size(H3_SIZE))
+					.push(text(format!("{name}: {pool}")))
+					.push(text(format!("{name}: {}", contest.actor)))
+					.push(text(format!("{opponent}: {opponent_pool}")))
+					.push(text(format!("{opponent}: {}", contest.opponent)));
+			}
+		}
+
+		if let Some(winner) = outcome.winner_name() {
+			col = col.push(text(winner));
+		}
+		if let Some(damage) = outcome.damage() {
+			col = col.push(text(format!("{}: {damage}", fl!("damage"))));
+		}
+
+		col.into()
+	}
+}
@@ -0,0 +1,798 @@
+use std::fmt::{self, Display};
+
+use cofd::{
+	character::{Damage, Wound},
+	prelude::*,
+};
+use iced::{
+	widget::{button, checkbox, column, pick_list, row, text, text_input, Column},
+	Alignment, Length,
+};
+
+use super::dice::character_name;
+use crate::{
+	catalog::styles,
+	component::{tilts, TiltsComponent},
+	extras::CharacterExtras,
+	fl,
+	fuel::FuelUse,
+	health,
+	i18n::{Translate, Translated},
+	modifiers,
+	tilts::{self as tilt, Tilt},
+	widget::track::HealthTrack,
+	Element, H2_SIZE, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
+};
+
+const DAMAGE_KINDS: [Wound; 3] = [Wound::Bashing, Wound::Lethal, Wound::Aggravated];
+
+#[derive(Debug, Clone)]
+pub enum Combatant {
+	/// Index into the roster.
+	Character(usize),
+	Npc {
+		name: String,
+		initiative: i32,
+		health: Damage,
+		max_health: usize,
+		tilts: Vec<Tilt>,
+	},
+}
+
+#[derive(Debug, Clone)]
+struct Participant {
+	id: usize,
+	combatant: Combatant,
+	/// Index into the character's weapons, for the initiative penalty.
+	weapon: Option<usize>,
+	/// The rolled die and the resulting initiative.
+	initiative: Option<(u8, i32)>,
+	delayed: bool,
+}
+
+impl Participant {
+	fn name(&self, characters: &[Character]) -> String {
+		match &self.combatant {
+			Combatant::Character(i) => characters.get(*i).map(character_name).unwrap_or_default(),
+			Combatant::Npc { name, .. } => name.clone(),
+		}
+	}
+
+	/// Initiative modifier plus the equipped weapon's initiative penalty.
+	fn modifier(&self, characters: &[Character], extras: &[CharacterExtras]) -> i32 {
+		match &self.combatant {
+			Combatant::Character(i) => characters.get(*i).map_or(0, |character| {
+				let weapon = self
+					.weapon
+					.and_then(|w| character.weapons.get(w))
+					.map_or(0, |weapon| i32::from(weapon.initative));
+
+				let initiative = extras.get(*i).map_or_else(
+					|| i32::from(character.initiative()),
+					|extras| i32::from(modifiers::initiative(character, extras)),
+				);
+
+				initiative + weapon
+			}),
+			Combatant::Npc { initiative, .. } => *initiative,
+		}
+	}
+
+	fn roll(&mut self, characters: &[Character], extras: &[CharacterExtras]) {
+		let die = fastrand::u8(1..=10);
+		self.initiative = Some((die, i32::from(die) + self.modifier(characters, extras)));
+	}
+
+	fn total(&self) -> Option<i32> {
+		self.initiative.map(|(_, total)| total)
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WeaponPick {
+	Unarmed,
+	Weapon(usize, String),
+}
+
+impl Display for WeaponPick {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Unarmed => f.write_str(&fl!("unarmed")),
+			Self::Weapon(_, name) => f.write_str(name),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RosterPick(usize, String);
+
+impl Display for RosterPick {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.1)
+	}
+}
+
+/// Turn order for a fight between roster characters and ad-hoc NPCs.
+#[derive(Debug, Clone)]
+pub struct EncounterView {
+	participants: Vec<Participant>,
+	next_id: usize,
+	round: u16,
+	turn: usize,
+
+	npc_name: String,
+	npc_initiative: i32,
+	npc_health: usize,
+
+	damage: u16,
+	damage_kind: Wound,
+
+	tilts: TiltsComponent,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+	Back,
+	Save,
+
+	AddCharacter(RosterPick),
+	NpcName(String),
+	NpcInitiative(i32),
+	NpcHealth(usize),
+	AddNpc,
+	Remove(usize),
+	Weapon(usize, WeaponPick),
+
+	RollInitiative,
+	Next,
+	Delay,
+	Act(usize),
+	End,
+
+	HealthChanged(usize, Wound),
+	DamageAmount(u16),
+	DamageKind(Wound),
+	ApplyDamage(usize),
+
+	Maneuver(usize, String, bool),
+
+	Tilts(usize, tilts::Message),
+}
+
+impl EncounterView {
+	pub fn new() -> Self {
+		Self {
+			participants: Vec::new(),
+			next_id: 0,
+			round: 0,
+			turn: 0,
+			npc_name: String::new(),
+			npc_initiative: 0,
+			npc_health: 7,
+			damage: 1,
+			damage_kind: Wound::Lethal,
+			tilts: TiltsComponent::new(),
+		}
+	}
+
+	fn add(&mut self, combatant: Combatant) {
+		self.participants.push(Participant {
+			id: self.next_id,
+			combatant,
+			weapon: None,
+			initiative: None,
+			delayed: false,
+		});
+		self.next_id += 1;
+	}
+
+	fn current_id(&self) -> Option<usize> {
+		self.participants.get(self.turn).map(|p| p.id)
+	}
+
+	/// Highest initiative goes first; ties go to the higher modifier. Unrolled participants wait at the end.
+	fn sort(&mut self, characters: &[Character], extras: &[CharacterExtras]) {
+		let current = self.current_id();
+
+		self.participants.sort_by_key(|p| {
+			std::cmp::Reverse((
+				p.total().unwrap_or(i32::MIN),
+				p.modifier(characters, extras),
+			))
+		});
+
+		if let Some(current) = current {
+			self.turn = self
+				.participants
+				.iter()
+				.position(|p| p.id == current)
+				.unwrap_or(0);
+		}
+	}
+
+	fn can_act(&self, i: usize) -> bool {
+		self.participants
+			.get(i)
+			.is_some_and(|p| p.initiative.is_some() && !p.delayed)
+	}
+
+	/// A round has passed for every Tilt and fuel limit in the fight.
+	fn tick(&mut self, extras: &mut [CharacterExtras]) {
+		for participant in &mut self.participants {
+			match &mut participant.combatant {
+				Combatant::Character(c) => {
+					if let Some(extras) = extras.get_mut(*c) {
+						tilt::tick(&mut extras.tilts);
+						extras.session.fuel.end_turn();
+					}
+				}
+				Combatant::Npc { tilts, .. } => tilt::tick(tilts),
+			}
+		}
+	}
+
+	fn next(&mut self, extras: &mut [CharacterExtras]) {
+		if self.round == 0 {
+			return;
+		}
+
+		self.turn += 1;
+		while self.turn < self.participants.len() && !self.can_act(self.turn) {
+			self.turn += 1;
+		}
+
+		if self.turn >= self.participants.len() {
+			// Anyone still holding their action has lost it.
+			for participant in &mut self.participants {
+				participant.delayed = false;
+			}
+
+			self.round += 1;
+			self.turn = 0;
+			self.tick(extras);
+			while self.turn < self.participants.len() && !self.can_act(self.turn) {
+				self.turn += 1;
+			}
+		}
+	}
+
+	#[allow(clippy::too_many_lines)]
+	pub fn update(
+		&mut self,
+		message: Message,
+		characters: &mut [Character],
+		extras: &mut [CharacterExtras],
+	) {
+		match message {
+			Message::Back | Message::Save => {}
+
+			Message::AddCharacter(RosterPick(i, _)) => self.add(Combatant::Character(i)),
+			Message::NpcName(name) => self.npc_name = name,
+			Message::NpcInitiative(initiative) => self.npc_initiative = initiative,
+			Message::NpcHealth(health) => self.npc_health = health,
+			Message::AddNpc => {
+				let name = if self.npc_name.is_empty() {
+					fl!("unnamed")
+				} else {
+					std::mem::take(&mut self.npc_name)
+				};
+
+				self.add(Combatant::Npc {
+					name,
+					initiative: self.npc_initiative,
+					health: Damage::default(),
+					max_health: self.npc_health,
+					tilts: Vec::new(),
+				});
+			}
+			Message::Remove(i) => {
+				if i < self.participants.len() {
+					self.participants.remove(i);
+					if i < self.turn {
+						self.turn -= 1;
+					}
+				}
+			}
+			Message::Weapon(i, weapon) => {
+				if let Some(participant) = self.participants.get_mut(i) {
+					participant.weapon = match weapon {
+						WeaponPick::Unarmed => None,
+						WeaponPick::Weapon(w, _) => Some(w),
+					};
+				}
+			}
+
+			Message::RollInitiative => {
+				let started = self.round > 0;
+
+				for participant in &mut self.participants {
+					if participant.initiative.is_none() || !started {
+						participant.roll(characters, extras);
+					}
+				}
+
+				self.sort(characters, extras);
+				if !started {
+					self.turn = 0;
+					self.round = 1;
+				}
+			}
+			Message::Next => self.next(extras),
+			Message::Delay => {
+				if let Some(participant) = self.participants.get_mut(self.turn) {
+					participant.delayed = true;
+				}
+				self.next(extras);
+			}
+			Message::Act(i) => {
+				// Acting from a delay takes the current participant's initiative from now on.
+				if i < self.participants.len() && self.participants[i].delayed {
+					let total = self
+						.participants
+						.get(self.turn)
+						.and_then(Participant::total);
+					let mut participant = self.participants.remove(i);
+
+					if i < self.turn {
+						self.turn -= 1;
+					}
+
+					participant.delayed = false;
+					if let (Some(total), Some((die, _))) = (total, participant.initiative) {
+						participant.initiative = Some((die, total));
+					}
+
+					self.participants.insert(self.turn, participant);
+				}
+			}
+			Message::End => {
+				// The fight was its own scene as far as fuel goes, and nobody keeps using a maneuver past it.
+				for participant in &self.participants {
+					if let Combatant::Character(c) = participant.combatant
+						&& let Some(extras) = extras.get_mut(c)
+					{
+						extras.session.fuel = FuelUse::default();
+						extras.maneuvers.clear();
+					}
+				}
+
+				self.participants.clear();
+				self.round = 0;
+				self.turn = 0;
+			}
+
+			Message::HealthChanged(i, wound) => {
+				if let Some(participant) = self.participants.get_mut(i) {
+					match &mut participant.combatant {
+						Combatant::Character(c) => {
+							if let Some(character) = characters.get_mut(*c) {
+								character.health_mut().poke(&wound);
+							}
+							// Marking an empty box is a fresh injury, which interrupts any rest.
+							if wound == Wound::None
+								&& let Some(extras) = extras.get_mut(*c)
+							{
+								extras.recovery.progress = 0;
+							}
+						}
+						Combatant::Npc { health, .. } => health.poke(&wound),
+					}
+				}
+			}
+			Message::DamageAmount(amount) => self.damage = amount,
+			Message::DamageKind(kind) => self.damage_kind = kind,
+			Message::ApplyDamage(i) => {
+				if let Some(participant) = self.participants.get_mut(i) {
+					match &mut participant.combatant {
+						Combatant::Character(c) => {
+							if let Some(character) = characters.get_mut(*c) {
+								let max = extras.get(*c).map_or(character.max_health(), |extras| {
+									modifiers::max_health(character, extras)
+								}) as usize;
+								health::wound(
+									character.health_mut(),
+									max,
+									self.damage_kind,
+									self.damage,
+								);
+							}
+							if let Some(extras) = extras.get_mut(*c) {
+								extras.recovery.progress = 0;
+							}
+						}
+						Combatant::Npc {
+							health, max_health, ..
+						} => health::wound(health, *max_health, self.damage_kind, self.damage),
+					}
+				}
+			}
+
+			Message::Maneuver(i, key, active) => {
+				if let Some(Participant {
+					combatant: Combatant::Character(c),
+					..
+				}) = self.participants.get(i)
+					&& let Some(extras) = extras.get_mut(*c)
+				{
+					extras.maneuvers.retain(|maneuver| *maneuver != key);
+					if active {
+						extras.maneuvers.push(key);
+					}
+				}
+			}
+
+			Message::Tilts(i, message) => {
+				if let Some(participant) = self.participants.get_mut(i) {
+					match &mut participant.combatant {
+						Combatant::Character(c) => {
+							if let Some(extras) = extras.get_mut(*c) {
+								self.tilts.update(message, &mut extras.tilts);
+							}
+						}
+						Combatant::Npc { tilts, .. } => self.tilts.update(message, tilts),
+					}
+				}
+			}
+		}
+	}
+
+	#[allow(clippy::too_many_lines)]
+	pub fn view<'a>(
+		&'a self,
+		characters: &'a [Character],
+		extras: &'a [CharacterExtras],
+	) -> Element<'a, Message> {
+		let roster: Vec<RosterPick> = characters
+			.iter()
+			.enumerate()
+			.filter(|(i, _)| {
+				!self
+					.participants
+					.iter()
+					.any(|p| matches!(p.combatant, Combatant::Character(c) if c == *i))
+			})
+			.map(|(i, character)| RosterPick(i, character_name(character)))
+			.collect();
+
+		let add = row![
+			pick_list(roster, None::<RosterPick>, Message::AddCharacter)
+				.placeholder(fl!("add-character"))
+				.padding(INPUT_PADDING),
+			text_input(&fl!("name"), &self.npc_name)
+				.on_input(Message::NpcName)
+				.padding(INPUT_PADDING)
+				.width(Length::Fixed(120.0)),
+			text(fl!("initiative")),
+			text_input("", &self.npc_initiative.to_string())
+				.on_input(|val| Message::NpcInitiative(val.parse().unwrap_or(0)))
+				.padding(INPUT_PADDING)
+				.width(Length::Fixed(40.0)),
+			text(fl!("health")),
+			text_input("", &self.npc_health.to_string())
+				.on_input(|val| Message::NpcHealth(val.parse().unwrap_or(0)))
+				.padding(INPUT_PADDING)
+				.width(Length::Fixed(40.0)),
+			button(text(fl!("add-npc")))
+				.padding(INPUT_PADDING)
+				.on_press(Message::AddNpc),
+		]
+		.spacing(5)
+		.align_items(Alignment::Center);
+
+		let kinds: Vec<Translated<Wound>> = DAMAGE_KINDS.into_iter().map(Into::into).collect();
+
+		let controls = row![
+			button(text(fl!("roll-initiative"))).on_press(Message::RollInitiative),
+			button(text(fl!("next-turn"))).on_press(Message::Next),
+			button(text(fl!("delay"))).on_press(Message::Delay),
+			button(text(fl!("end-encounter"))).on_press(Message::End),
+			text(format!("{}:", fl!("damage"))),
+			text_input("", &self.damage.to_string())
+				.on_input(|val| Message::DamageAmount(val.parse().unwrap_or(0)))
+				.padding(INPUT_PADDING)
+				.width(Length::Fixed(40.0)),
+			pick_list(
+				kinds,
+				Some::<Translated<Wound>>(self.damage_kind.into()),
+				|val| Message::DamageKind(*val)
+			)
+			.padding(INPUT_PADDING),
+		]
+		.spacing(5)
+		.align_items(Alignment::Center);
+
+		let mut list = Column::new().spacing(5);
+		for (i, participant) in self.participants.iter().enumerate() {
+			list = list.push(self.participant(i, participant, characters, extras));
+		}
+
+		let title = if self.round > 0 {
+			format!(
+				"{} - {}",
+				fl!("encounter"),
+				fl!("round", round = self.round)
+			)
+		} else {
+			fl!("encounter")
+		};
+
+		column![
+			row![
+				button("Back").on_press(Message::Back),
+				button("Save").on_press(Message::Save),
+			],
+			column![text(title).size(H2_SIZE), add, controls, list]
+				.spacing(TITLE_SPACING)
+				.align_items(Alignment::Center)
+				.width(Length::Fill),
+		]
+		.width(Length::Fill)
+		.spacing(1)
+		.into()
+	}
+
+	#[allow(clippy::too_many_lines)]
+	fn participant<'a>(
+		&'a self,
+		i: usize,
+		participant: &'a Participant,
+		characters: &'a [Character],
+		extras: &'a [CharacterExtras],
+	) -> Element<'a, Message> {
+		let current = self.round > 0 && i == self.turn;
+
+		let mut name = text(participant.name(characters));
+		if current {
+			name = name.size(H3_SIZE);
+		}
+
+		let modifier = participant.modifier(characters, extras);
+		let initiative = match participant.initiative {
+			Some((die, total)) => format!("{die} + {modifier} = {total}"),
+			None => format!("{modifier:+}"),
+		};
+
+		let mut row = row![
+			text(if current { ">" } else { "" }).width(Length::Fixed(10.0)),
+			name.width(Length::FillPortion(2)),
+		]
+		.spacing(5)
+		.align_items(Alignment::Center);
+
+		let mut maneuvers = row![].spacing(5).align_items(Alignment::Center);
+
+		let (track, tilts) = match &participant.combatant {
+			Combatant::Character(c) => {
+				let character = &characters[*c];
+				let tilts = extras.get(*c).map_or(&[][..], |extras| &extras.tilts);
+
+				if let Some(extras) = extras.get(*c) {
+					row = row.push(
+						text(fl!(
+							"defense-value",
+							defense = modifiers::defense(character, extras)
+						))
+						.width(Length::Fill),
+					);
+
+					for maneuver in styles::unlocked(character)
+						.into_iter()
+						.filter(|maneuver| !maneuver.passive)
+					{
+						let key = maneuver.key.clone();
+						maneuvers = maneuvers.push(
+							checkbox(
+								maneuver.translated(),
+								extras.maneuvers.contains(&maneuver.key),
+							)
+							.on_toggle(move |active| Message::Maneuver(i, key.clone(), active)),
+						);
+					}
+				}
+
+				let mut weapons = vec![WeaponPick::Unarmed];
+				weapons.extend(
+					character
+						.weapons
+						.iter()
+						.enumerate()
+						.map(|(w, weapon)| WeaponPick::Weapon(w, weapon.name.clone())),
+				);
+				let selected = participant.weapon.and_then(|w| weapons.get(w + 1).cloned());
+
+				row = row.push(
+					pick_list(
+						weapons,
+						Some(selected.unwrap_or(WeaponPick::Unarmed)),
+						move |val| Message::Weapon(i, val),
+					)
+					.padding(INPUT_PADDING)
+					.width(Length::Fill),
+				);
+
+				let track = HealthTrack::new(
+					character.health().clone(),
+					extras.get(*c).map_or(character.max_health(), |extras| {
+						modifiers::max_health(character, extras)
+					}) as usize,
+					move |wound| Message::HealthChanged(i, wound),
+				);
+
+				(track, tilts)
+			}
+			Combatant::Npc {
+				health,
+				max_health,
+				tilts,
+				..
+			} => {
+				// No weapon or Defense to show.
+				row = row
+					.push(column![].width(Length::Fill))
+					.push(column![].width(Length::Fill));
+				let track = HealthTrack::new(health.clone(), *max_health, move |wound| {
+					Message::HealthChanged(i, wound)
+				});
+
+				(track, &tilts[..])
+			}
+		};
+
+		row = row.push(text(initiative).width(Length::Fill));
+
+		row = if participant.delayed {
+			row.push(button(text(fl!("act"))).on_press(Message::Act(i)))
+		} else {
+			row.push(text(if current {
+				fl!("acting")
+			} else {
+				String::new()
+			}))
+		};
+
+		let row = row
+			.push(track)
+			.push(
+				button(text(fl!("apply-damage")))
+					.padding(INPUT_PADDING)
+					.on_press(Message::ApplyDamage(i)),
+			)
+			.push(
+				button(text(fl!("remove")))
+					.padding(INPUT_PADDING)
+					.on_press(Message::Remove(i)),
+			);
+
+		column![
+			row,
+			maneuvers,
+			self.tilts
+				.view(tilts)
+				.map(move |message| Message::Tilts(i, message))
+		]
+		.spacing(3)
+		.into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// An encounter with NPCs of the given initiative modifiers and rolled totals.
+	fn encounter(npcs: &[(i32, Option<i32>)]) -> EncounterView {
+		let mut view = EncounterView::new();
+		for (i, (modifier, total)) in npcs.iter().enumerate() {
+			view.add(Combatant::Npc {
+				name: i.to_string(),
+				initiative: *modifier,
+				health: Damage::default(),
+				max_health: 7,
+				tilts: Vec::new(),
+			});
+			view.participants[i].initiative = total.map(|total| (1, total));
+		}
+		view.sort(&[], &[]);
+		view.turn = 0;
+		view.round = 1;
+		view
+	}
+
+	fn order(view: &EncounterView) -> Vec<usize> {
+		view.participants.iter().map(|p| p.id).collect()
+	}
+
+	fn update(view: &mut EncounterView, message: Message) {
+		view.update(message, &mut [], &mut []);
+	}
+
+	#[test]
+	fn highest_initiative_goes_first() {
+		let view = encounter(&[(2, Some(8)), (1, None), (5, Some(12)), (3, Some(8))]);
+
+		// 3 and 0 tie on 8, so the higher modifier goes first; 1 hasn't rolled.
+		assert_eq!(order(&view), vec![2, 3, 0, 1]);
+		assert_eq!(view.turn, 0);
+	}
+
+	#[test]
+	fn next_skips_who_cant_act_and_starts_a_new_round() {
+		let mut view = encounter(&[(0, Some(10)), (0, Some(8)), (0, None), (0, Some(5))]);
+		view.participants[1].delayed = true;
+
+		update(&mut view, Message::Next);
+		assert_eq!(view.participants[view.turn].id, 3);
+
+		update(&mut view, Message::Next);
+		assert_eq!(view.round, 2);
+		assert_eq!(view.turn, 0);
+		assert!(view.participants.iter().all(|p| !p.delayed));
+
+		update(&mut view, Message::Next);
+		assert_eq!(view.participants[view.turn].id, 1);
+	}
+
+	#[test]
+	fn acting_from_a_delay_takes_the_current_initiative() {
+		let mut view = encounter(&[(0, Some(10)), (0, Some(8)), (0, Some(5))]);
+
+		update(&mut view, Message::Delay);
+		assert!(view.participants[0].delayed);
+		assert_eq!(view.turn, 1);
+
+		update(&mut view, Message::Next);
+		assert_eq!(view.turn, 2);
+
+		update(&mut view, Message::Act(0));
+		assert_eq!(order(&view), vec![1, 0, 2]);
+		assert_eq!(view.turn, 1);
+		assert_eq!(view.participants[1].total(), Some(5));
+		assert!(!view.participants[1].delayed);
+	}
+
+	#[test]
+	fn removing_someone_keeps_the_turn() {
+		let mut view = encounter(&[(0, Some(10)), (0, Some(8)), (0, Some(5)), (0, Some(3))]);
+		update(&mut view, Message::Next);
+		update(&mut view, Message::Next);
+		assert_eq!(view.participants[view.turn].id, 2);
+
+		// Someone who already acted, then someone still to come.
+		update(&mut view, Message::Remove(0));
+		assert_eq!(view.participants[view.turn].id, 2);
+		update(&mut view, Message::Remove(2));
+		assert_eq!(view.participants[view.turn].id, 2);
+
+		// Whoever was up is gone, so the next turn starts a new round.
+		update(&mut view, Message::Remove(1));
+		assert_eq!(order(&view), vec![1]);
+		update(&mut view, Message::Next);
+		assert_eq!(view.round, 2);
+		assert_eq!(view.participants[view.turn].id, 1);
+	}
+
+	#[test]
+	fn marking_a_box_interrupts_rest() {
+		let mut view = EncounterView::new();
+		view.add(Combatant::Character(0));
+		let mut characters = [Character::default()];
+		let mut extras = [CharacterExtras::default()];
+		extras[0].recovery.progress = 30;
+
+		view.update(
+			Message::HealthChanged(0, Wound::None),
+			&mut characters,
+			&mut extras,
+		);
+		assert_eq!(extras[0].recovery.progress, 0);
+
+		// Making a wound worse isn't a new one.
+		extras[0].recovery.progress = 30;
+		view.update(
+			Message::HealthChanged(0, Wound::Bashing),
+			&mut characters,
+			&mut extras,
+		);
+		assert_eq!(extras[0].recovery.progress, 30);
+	}
+}
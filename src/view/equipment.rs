@@ -1,27 +1,83 @@
+use std::fmt::{self, Display};
+
 use closure::closure;
 use cofd::{character::Weapon, prelude::*};
 use iced::{
-	widget::{column, row, text, text_input},
-	Alignment, Length,
+	theme,
+	widget::{button, checkbox, column, pick_list, row, text, text_input},
+	Alignment, Color, Length,
+};
+
+use super::{dice::character_name, overview::vec_changed};
+use crate::{
+	catalog::{equipment::Entry, EQUIPMENT},
+	dice::{expr, Again, Opposition, Pool, PoolSpec, PoolTrait, Request, Roll, WeaponDamage},
+	extras::CharacterExtras,
+	fl,
+	i18n::{Translate, Translated},
+	items::{Armor, Carry, Item},
+	modifiers,
+	resources::{self, Check},
+	training, Element, H2_SIZE, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
 };
 
-use super::overview::vec_changed;
-use crate::{fl, Element, H2_SIZE, TITLE_SPACING};
+/// How many catalog matches to list at once.
+const SEARCH_RESULTS: usize = 8;
 
 #[derive(Debug, Clone)]
-pub struct EquipmentTab;
+pub struct EquipmentTab {
+	search: String,
+	purchase: Option<Entry>,
+	/// A purchase roll that came up short, shown until the dialog closes.
+	failed: Option<Roll>,
+}
+
+/// A Skill for an equipment bonus to apply to, or none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BonusSkill(Option<Skill>);
+
+impl Display for BonusSkill {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.0 {
+			Some(skill) => f.write_str(&skill.translated()),
+			None => f.write_str(&fl!("none")),
+		}
+	}
+}
 
 #[derive(Debug, Clone)]
 pub enum Message {
 	WeaponChanged(usize, Weapon),
+	ArmorChanged(usize, Armor),
+	ItemChanged(usize, Item),
+	Search(String),
+	AddEntry(Entry),
+	Purchase(Option<Entry>),
+	Buy(Entry),
+	/// Rolls Wits + Streetwise for something above the character's means.
+	RollPurchase(Entry),
+	/// Gets something above the character's means by reducing Resources.
+	Reduce(Entry),
+	Recover(usize),
+	/// Handled by the sheet, which hands it over to the dice tab.
+	Roll(Request),
 }
 
 impl EquipmentTab {
 	pub fn new() -> Self {
-		Self
+		Self {
+			search: String::new(),
+			purchase: None,
+			failed: None,
+		}
 	}
 
-	pub fn update(&mut self, event: Message, character: &mut Character) {
+	pub fn update(
+		&mut self,
+		event: Message,
+		character: &mut Character,
+		extras: &mut CharacterExtras,
+	) {
 		match event {
 			Message::WeaponChanged(i, weapon) => {
 				if weapon == Default::default() {
@@ -30,11 +86,85 @@ impl EquipmentTab {
 					vec_changed(i, weapon, &mut character.weapons);
 				}
 			}
+			Message::ArmorChanged(i, armor) => {
+				if armor == Default::default() {
+					// The blank row at the end has nothing to remove.
+					if i < extras.armor.len() {
+						extras.armor.remove(i);
+					}
+				} else {
+					// Only one suit of armor can be worn at a time.
+					if armor.equipped {
+						for other in &mut extras.armor {
+							other.equipped = false;
+						}
+					}
+					vec_changed(i, armor, &mut extras.armor);
+				}
+			}
+			Message::ItemChanged(i, item) => {
+				if item == Default::default() {
+					if i < extras.items.len() {
+						extras.items.remove(i);
+					}
+				} else {
+					vec_changed(i, item, &mut extras.items);
+				}
+			}
+			Message::Search(search) => self.search = search,
+			Message::AddEntry(entry) => add_entry(entry, character, extras),
+			Message::Purchase(entry) => {
+				self.purchase = entry;
+				self.failed = None;
+			}
+			Message::Buy(entry) => {
+				if let Some(availability) = EQUIPMENT.availability(entry)
+					&& resources::check(availability, resources::effective(character, extras))
+						== Check::Free
+				{
+					add_entry(entry, character, extras);
+					self.purchase = None;
+				}
+			}
+			Message::RollPurchase(entry) => {
+				if let Some(availability) = EQUIPMENT.availability(entry) {
+					let pool = purchase_pool(character, extras, availability);
+					let roll = Roll::new(pool.total(), Again::default());
+
+					if roll.successes() > 0 {
+						add_entry(entry, character, extras);
+						self.purchase = None;
+						self.failed = None;
+					} else {
+						self.failed = Some(roll);
+					}
+				}
+			}
+			Message::Reduce(entry) => {
+				if let Some(name) = EQUIPMENT.name(entry)
+					&& resources::can_reduce(character, extras)
+				{
+					resources::reduce(extras, name);
+					add_entry(entry, character, extras);
+					self.purchase = None;
+					self.failed = None;
+				}
+			}
+			Message::Recover(i) => {
+				if i < extras.reductions.len() {
+					extras.reductions.remove(i);
+				}
+			}
+			Message::Roll(_) => {}
 		}
 	}
 
 	#[allow(clippy::too_many_lines)]
-	pub fn view(&self, character: &Character) -> Element<Message> {
+	pub fn view<'a>(
+		&'a self,
+		character: &'a Character,
+		extras: &'a CharacterExtras,
+	) -> Element<'a, Message> {
 		let weapons = {
 			let mut name = column![text("Weapon/Attack")]
 				.width(Length::FillPortion(3))
@@ -44,6 +174,10 @@ impl EquipmentTab {
 				.width(Length::Fill)
 				.align_items(Alignment::Center)
 				.spacing(3);
+			let mut total = column![text(fl!("roll"))]
+				.width(Length::Fill)
+				.align_items(Alignment::Center)
+				.spacing(3);
 			let mut damage = column![text("Damage")]
 				.width(Length::Fill)
 				.align_items(Alignment::Center)
@@ -79,6 +213,7 @@ impl EquipmentTab {
 						Message::WeaponChanged(i, weapon)
 					}),
 				));
+				total = total.push(Self::roll(character, extras, &weapon));
 				damage = damage.push(text_input("", &weapon.damage).on_input(
 					closure!(clone weapon, |val| {
 						let mut weapon = weapon.clone();
@@ -115,7 +250,7 @@ impl EquipmentTab {
 
 			column![
 				text("Combat").size(H2_SIZE),
-				row![name, pool, damage, range, initative, size]
+				row![name, pool, total, damage, range, initative, size]
 					.spacing(5)
 					.padding(5)
 			]
@@ -123,6 +258,458 @@ impl EquipmentTab {
 			.spacing(TITLE_SPACING)
 		};
 
-		column![weapons].align_items(Alignment::Center).into()
+		column![
+			self.catalog(character, extras),
+			weapons,
+			self.armor(extras),
+			self.inventory(extras)
+		]
+		.align_items(Alignment::Center)
+		.into()
+	}
+
+	/// Search box over the bundled catalog; picking an entry adds a copy that can then be edited.
+	fn catalog<'a>(
+		&'a self,
+		character: &'a Character,
+		extras: &'a CharacterExtras,
+	) -> Element<'a, Message> {
+		let mut results = column![].spacing(3).align_items(Alignment::Center);
+
+		if !self.search.is_empty() {
+			for (entry, name) in EQUIPMENT
+				.search(&self.search)
+				.into_iter()
+				.take(SEARCH_RESULTS)
+			{
+				let kind = match entry {
+					Entry::Weapon(_) => fl!("weapon"),
+					Entry::Armor(_) => fl!("armor"),
+					Entry::Gear(_) => fl!("gear"),
+				};
+
+				results = results.push(
+					row![
+						button(text(format!("{name} ({kind})")))
+							.padding(INPUT_PADDING)
+							.on_press(Message::AddEntry(entry)),
+						button(text(fl!("buy")))
+							.padding(INPUT_PADDING)
+							.on_press(Message::Purchase(Some(entry))),
+					]
+					.spacing(3),
+				);
+			}
+		}
+
+		column![
+			text_input(&fl!("search-catalog"), &self.search)
+				.on_input(Message::Search)
+				.padding(INPUT_PADDING)
+				.width(Length::Fixed(300.0)),
+			results,
+			self.resources(character, extras),
+		]
+		.spacing(TITLE_SPACING)
+		.align_items(Alignment::Center)
+		.into()
+	}
+
+	/// Resources left after recent purchases, and the purchase dialog when one is open.
+	fn resources<'a>(
+		&'a self,
+		character: &'a Character,
+		extras: &'a CharacterExtras,
+	) -> Element<'a, Message> {
+		let effective = resources::effective(character, extras);
+		let mut col = column![text(fl!(
+			"resources-left",
+			rating = resources::rating(character),
+			effective = effective
+		))]
+		.spacing(3)
+		.align_items(Alignment::Center);
+
+		for (i, reduction) in extras.reductions.iter().enumerate() {
+			col = col.push(
+				row![
+					text(format!("-{} ({})", reduction.amount, reduction.item)),
+					button(text(fl!("recover")))
+						.padding(INPUT_PADDING)
+						.on_press(Message::Recover(i)),
+				]
+				.spacing(5)
+				.align_items(Alignment::Center),
+			);
+		}
+
+		if let Some(entry) = self.purchase
+			&& let (Some(name), Some(availability)) =
+				(EQUIPMENT.name(entry), EQUIPMENT.availability(entry))
+		{
+			let mut buttons = row![].spacing(5);
+			let verdict = match resources::check(availability, effective) {
+				Check::Free => {
+					buttons = buttons.push(
+						button(text(fl!("buy")))
+							.padding(INPUT_PADDING)
+							.on_press(Message::Buy(entry)),
+					);
+					fl!("purchase-free")
+				}
+				Check::Above => {
+					let pool = purchase_pool(character, extras, availability);
+					buttons = buttons.push(
+						button(text(format!("{} ({})", fl!("roll"), pool.total())))
+							.padding(INPUT_PADDING)
+							.on_press(Message::RollPurchase(entry)),
+					);
+					if resources::can_reduce(character, extras) {
+						buttons = buttons.push(
+							button(text(fl!("reduce-resources")))
+								.padding(INPUT_PADDING)
+								.on_press(Message::Reduce(entry)),
+						);
+					}
+					fl!("purchase-above")
+				}
+			};
+
+			col = col.push(
+				column![
+					text(format!("{name}: {} {availability}", fl!("availability"))).size(H3_SIZE),
+					text(verdict),
+				]
+				.push_maybe(self.failed.as_ref().map(|roll| {
+					text(fl!("purchase-failed", roll = roll.to_string()))
+						.style(theme::Text::Color(Color::from_rgb(0.8, 0.0, 0.0)))
+				}))
+				.push(
+					buttons.push(
+						button(text(fl!("cancel")))
+							.padding(INPUT_PADDING)
+							.on_press(Message::Purchase(None)),
+					),
+				)
+				.spacing(3)
+				.align_items(Alignment::Center),
+			);
+		}
+
+		col.into()
+	}
+
+	#[allow(clippy::too_many_lines)]
+	fn armor(&self, extras: &CharacterExtras) -> Element<Message> {
+		let mut name = column![text(fl!("name"))]
+			.width(Length::FillPortion(3))
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut rating = column![text(fl!("armor-rating"))]
+			.width(Length::Fill)
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut strength = column![text(fl!("strength"))]
+			.width(Length::Fill)
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut defense = column![text(fl!("defense"))]
+			.width(Length::Fill)
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut speed = column![text(fl!("speed"))]
+			.width(Length::Fill)
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut coverage = column![text(fl!("coverage"))]
+			.width(Length::FillPortion(2))
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut equipped = column![text(fl!("equipped"))]
+			.width(Length::Fill)
+			.align_items(Alignment::Center)
+			.spacing(3);
+
+		let mut vec = extras.armor.clone();
+		vec.push(Default::default());
+
+		for (i, armor) in vec.into_iter().enumerate() {
+			name = name.push(
+				text_input("", &armor.name).on_input(closure!(clone armor, |val| {
+					let mut armor = armor.clone();
+					armor.name = val;
+					Message::ArmorChanged(i, armor)
+				})),
+			);
+			rating = rating.push(
+				row![
+					text_input("", &armor.general.to_string()).on_input(
+						closure!(clone armor, |val| {
+							let mut armor = armor.clone();
+							armor.general = val.parse().unwrap_or(0);
+							Message::ArmorChanged(i, armor)
+						})
+					),
+					text("/"),
+					text_input("", &armor.ballistic.to_string()).on_input(
+						closure!(clone armor, |val| {
+							let mut armor = armor.clone();
+							armor.ballistic = val.parse().unwrap_or(0);
+							Message::ArmorChanged(i, armor)
+						})
+					),
+				]
+				.align_items(Alignment::Center),
+			);
+			strength = strength.push(text_input("", &armor.strength.to_string()).on_input(
+				closure!(clone armor, |val| {
+					let mut armor = armor.clone();
+					armor.strength = val.parse().unwrap_or(0);
+					Message::ArmorChanged(i, armor)
+				}),
+			));
+			defense = defense.push(text_input("", &armor.defense.to_string()).on_input(
+				closure!(clone armor, |val| {
+					let mut armor = armor.clone();
+					armor.defense = val.parse().unwrap_or(0);
+					Message::ArmorChanged(i, armor)
+				}),
+			));
+			speed = speed.push(text_input("", &armor.speed.to_string()).on_input(
+				closure!(clone armor, |val| {
+					let mut armor = armor.clone();
+					armor.speed = val.parse().unwrap_or(0);
+					Message::ArmorChanged(i, armor)
+				}),
+			));
+			coverage = coverage.push(text_input("", &armor.coverage).on_input(
+				closure!(clone armor, |val| {
+					let mut armor = armor.clone();
+					armor.coverage = val;
+					Message::ArmorChanged(i, armor)
+				}),
+			));
+			equipped = equipped.push(checkbox("", armor.equipped).on_toggle(
+				closure!(clone armor, |val| {
+					let mut armor = armor.clone();
+					armor.equipped = val;
+					Message::ArmorChanged(i, armor)
+				}),
+			));
+		}
+
+		column![
+			text(fl!("armor")).size(H2_SIZE),
+			row![name, rating, strength, defense, speed, coverage, equipped]
+				.spacing(5)
+				.padding(5)
+		]
+		.align_items(Alignment::Center)
+		.spacing(TITLE_SPACING)
+		.into()
+	}
+
+	#[allow(clippy::too_many_lines)]
+	fn inventory(&self, extras: &CharacterExtras) -> Element<Message> {
+		let mut name = column![text(fl!("name"))]
+			.width(Length::FillPortion(3))
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut size = column![text(fl!("size"))]
+			.width(Length::Fill)
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut durability = column![text(fl!("durability"))]
+			.width(Length::Fill)
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut structure = column![text(fl!("structure"))]
+			.width(Length::Fill)
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut availability = column![text(fl!("availability"))]
+			.width(Length::Fill)
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut bonus = column![text(fl!("equipment-bonus"))]
+			.width(Length::FillPortion(3))
+			.align_items(Alignment::Center)
+			.spacing(3);
+		let mut state = column![text(fl!("state"))]
+			.width(Length::FillPortion(2))
+			.align_items(Alignment::Center)
+			.spacing(3);
+
+		let mut skills = vec![BonusSkill(None)];
+		skills.extend(
+			Skill::all()
+				.into_iter()
+				.map(|skill| BonusSkill(Some(skill))),
+		);
+		let states: Vec<Translated<Carry>> = Carry::all().into_iter().map(Into::into).collect();
+
+		let mut vec = extras.items.clone();
+		vec.push(Default::default());
+
+		for (i, item) in vec.into_iter().enumerate() {
+			name = name.push(
+				text_input("", &item.name).on_input(closure!(clone item, |val| {
+					let mut item = item.clone();
+					item.name = val;
+					Message::ItemChanged(i, item)
+				})),
+			);
+			size = size.push(text_input("", &item.size.to_string()).on_input(
+				closure!(clone item, |val| {
+					let mut item = item.clone();
+					item.size = val.parse().unwrap_or(0);
+					Message::ItemChanged(i, item)
+				}),
+			));
+			durability = durability.push(text_input("", &item.durability.to_string()).on_input(
+				closure!(clone item, |val| {
+					let mut item = item.clone();
+					item.durability = val.parse().unwrap_or(0);
+					Message::ItemChanged(i, item)
+				}),
+			));
+			structure = structure.push(text_input("", &item.structure.to_string()).on_input(
+				closure!(clone item, |val| {
+					let mut item = item.clone();
+					item.structure = val.parse().unwrap_or(0);
+					Message::ItemChanged(i, item)
+				}),
+			));
+			availability =
+				availability.push(text_input("", &item.availability.to_string()).on_input(
+					closure!(clone item, |val| {
+						let mut item = item.clone();
+						item.availability = val.parse().unwrap_or(0);
+						Message::ItemChanged(i, item)
+					}),
+				));
+			bonus = bonus.push(
+				row![
+					pick_list(
+						skills.clone(),
+						Some(BonusSkill(item.skill)),
+						closure!(clone item, |val: BonusSkill| {
+							let mut item = item.clone();
+							item.skill = val.0;
+							Message::ItemChanged(i, item)
+						})
+					)
+					.padding(INPUT_PADDING)
+					.width(Length::Fill),
+					text_input("", &item.bonus.to_string())
+						.on_input(closure!(clone item, |val| {
+							let mut item = item.clone();
+							item.bonus = val.parse().unwrap_or(0);
+							Message::ItemChanged(i, item)
+						}))
+						.width(Length::Fixed(30.0)),
+				]
+				.spacing(3)
+				.align_items(Alignment::Center),
+			);
+			state = state.push(
+				pick_list(
+					states.clone(),
+					Some::<Translated<Carry>>(item.state.into()),
+					closure!(clone item, |val: Translated<Carry>| {
+						let mut item = item.clone();
+						item.state = *val;
+						Message::ItemChanged(i, item)
+					}),
+				)
+				.padding(INPUT_PADDING)
+				.width(Length::Fill),
+			);
+		}
+
+		column![
+			text(fl!("inventory")).size(H2_SIZE),
+			row![
+				name,
+				size,
+				durability,
+				structure,
+				availability,
+				bonus,
+				state
+			]
+			.spacing(5)
+			.padding(5)
+		]
+		.align_items(Alignment::Center)
+		.spacing(TITLE_SPACING)
+		.into()
+	}
+
+	/// The weapon's dice pool worked out against the sheet, as a button that rolls it.
+	fn roll<'a>(
+		character: &Character,
+		extras: &CharacterExtras,
+		weapon: &Weapon,
+	) -> Element<'a, Message> {
+		if weapon.dice_pool.trim().is_empty() {
+			return text("").into();
+		}
+
+		let eval = expr::evaluate(&weapon.dice_pool, character, extras);
+		if !eval.is_valid() {
+			return text(fl!("unknown-traits", traits = eval.unknown.join(", ")))
+				.style(theme::Text::Color(Color::from_rgb(0.8, 0.0, 0.0)))
+				.into();
+		}
+
+		let again = training::again(character, &eval.skills, Again::default());
+		let pool = modifiers::combat_pool(eval.pool, character, extras);
+		let name = if weapon.name.is_empty() {
+			character_name(character)
+		} else {
+			format!("{} ({})", character_name(character), weapon.name)
+		};
+
+		button(text(pool.total()))
+			.padding(INPUT_PADDING)
+			.on_press(Message::Roll(Request {
+				name,
+				pool,
+				again,
+				opposition: Opposition::None,
+				damage: WeaponDamage::parse(&weapon.damage),
+			}))
+			.into()
+	}
+}
+
+/// Wits + Streetwise, less the Availability above the character's Resources.
+fn purchase_pool(character: &Character, extras: &CharacterExtras, availability: u16) -> Pool {
+	PoolSpec {
+		attribute: Some(Attribute::Wits),
+		other: Some(PoolTrait::Skill(Skill::Streetwise)),
+		modifier: -resources::shortfall(availability, resources::effective(character, extras)),
+	}
+	.build(character, extras)
+}
+
+fn add_entry(entry: Entry, character: &mut Character, extras: &mut CharacterExtras) {
+	match entry {
+		Entry::Weapon(i) => {
+			if let Some(entry) = EQUIPMENT.weapons.get(i) {
+				character.weapons.push(entry.weapon());
+			}
+		}
+		Entry::Armor(i) => {
+			if let Some(entry) = EQUIPMENT.armor.get(i) {
+				extras.armor.push(entry.armor());
+			}
+		}
+		Entry::Gear(i) => {
+			if let Some(entry) = EQUIPMENT.gear.get(i) {
+				extras.items.push(entry.item());
+			}
+		}
 	}
 }
@@ -1,6 +1,9 @@
 pub mod character_list;
 pub mod creator;
+pub mod dice;
+pub mod encounter;
 pub mod equipment;
+pub mod notes;
 pub mod overview;
 pub mod sheet;
 pub mod splat_extras;
@@ -0,0 +1,238 @@
+use iced::{
+	widget::{button, column, row, scrollable, text, text_editor, text_input, Column},
+	Alignment, Length,
+};
+
+use crate::{
+	extras::CharacterExtras,
+	fl,
+	notes::{Entry, Notes, Section},
+	Element, H2_SIZE, H3_SIZE, INPUT_PADDING, TITLE_SPACING,
+};
+
+const SECTION_HEIGHT: f32 = 120.0;
+
+#[derive(Debug)]
+pub struct NotesTab {
+	search: String,
+	background: text_editor::Content,
+	description: text_editor::Content,
+	goals: text_editor::Content,
+	/// The journal entry being edited.
+	entry: Option<(usize, text_editor::Content)>,
+}
+
+// Editor contents can't be cloned, but they can be rebuilt from their text.
+impl Clone for NotesTab {
+	fn clone(&self) -> Self {
+		Self {
+			search: self.search.clone(),
+			background: text_editor::Content::with_text(&self.background.text()),
+			description: text_editor::Content::with_text(&self.description.text()),
+			goals: text_editor::Content::with_text(&self.goals.text()),
+			entry: self
+				.entry
+				.as_ref()
+				.map(|(i, content)| (*i, text_editor::Content::with_text(&content.text()))),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+	Edit(Section, text_editor::Action),
+	Search(String),
+	NewEntry,
+	Open(usize),
+	Close,
+	Date(String),
+	Title(String),
+	EditEntry(text_editor::Action),
+	Delete,
+}
+
+fn section_name(section: Section) -> String {
+	match section {
+		Section::Background => fl!("background"),
+		Section::Description => fl!("description"),
+		Section::Goals => fl!("goals"),
+	}
+}
+
+impl NotesTab {
+	pub fn new(notes: &Notes) -> Self {
+		Self {
+			search: String::new(),
+			background: text_editor::Content::with_text(&notes.background),
+			description: text_editor::Content::with_text(&notes.description),
+			goals: text_editor::Content::with_text(&notes.goals),
+			entry: None,
+		}
+	}
+
+	fn content(&self, section: Section) -> &text_editor::Content {
+		match section {
+			Section::Background => &self.background,
+			Section::Description => &self.description,
+			Section::Goals => &self.goals,
+		}
+	}
+
+	fn content_mut(&mut self, section: Section) -> &mut text_editor::Content {
+		match section {
+			Section::Background => &mut self.background,
+			Section::Description => &mut self.description,
+			Section::Goals => &mut self.goals,
+		}
+	}
+
+	fn open(&mut self, notes: &Notes, i: usize) {
+		self.entry = notes
+			.entries
+			.get(i)
+			.map(|entry| (i, text_editor::Content::with_text(&entry.text)));
+	}
+
+	pub fn update(&mut self, message: Message, extras: &mut CharacterExtras) {
+		let notes = &mut extras.notes;
+
+		match message {
+			Message::Edit(section, action) => {
+				let is_edit = action.is_edit();
+				let content = self.content_mut(section);
+
+				content.perform(action);
+				if is_edit {
+					*notes.section_mut(section) = content.text();
+				}
+			}
+			Message::Search(search) => self.search = search,
+			Message::NewEntry => {
+				notes.entries.push(Entry::new());
+				self.open(notes, notes.entries.len() - 1);
+			}
+			Message::Open(i) => self.open(notes, i),
+			Message::Close => self.entry = None,
+			Message::Date(date) => {
+				if let Some((i, _)) = &self.entry
+					&& let Some(entry) = notes.entries.get_mut(*i)
+				{
+					entry.date = date;
+				}
+			}
+			Message::Title(title) => {
+				if let Some((i, _)) = &self.entry
+					&& let Some(entry) = notes.entries.get_mut(*i)
+				{
+					entry.title = title;
+				}
+			}
+			Message::EditEntry(action) => {
+				if let Some((i, content)) = &mut self.entry {
+					let is_edit = action.is_edit();
+
+					content.perform(action);
+					if is_edit && let Some(entry) = notes.entries.get_mut(*i) {
+						entry.text = content.text();
+					}
+				}
+			}
+			Message::Delete => {
+				if let Some((i, _)) = self.entry.take()
+					&& i < notes.entries.len()
+				{
+					notes.entries.remove(i);
+				}
+			}
+		}
+	}
+
+	pub fn view(&self, extras: &CharacterExtras) -> Element<Message> {
+		let notes = &extras.notes;
+
+		let mut sections = Column::new().spacing(TITLE_SPACING).width(Length::Fill);
+		for section in Section::all() {
+			sections = sections
+				.push(text(section_name(section)).size(H3_SIZE))
+				.push(
+					text_editor(self.content(section))
+						.on_action(move |action| Message::Edit(section, action))
+						.height(Length::Fixed(SECTION_HEIGHT)),
+				);
+		}
+
+		let mut entries = Column::new().spacing(3);
+		for i in notes.search(&self.search) {
+			let entry = &notes.entries[i];
+			let title = if entry.title.is_empty() {
+				fl!("untitled")
+			} else {
+				entry.title.clone()
+			};
+
+			entries = entries.push(
+				button(text(format!("{} {title}", entry.date)))
+					.on_press(Message::Open(i))
+					.padding(INPUT_PADDING)
+					.width(Length::Fill),
+			);
+		}
+
+		let mut journal = column![
+			text(fl!("journal")).size(H3_SIZE),
+			row![
+				text_input(&fl!("search-notes"), &self.search)
+					.on_input(Message::Search)
+					.padding(INPUT_PADDING),
+				button(text(fl!("new-entry")))
+					.on_press(Message::NewEntry)
+					.padding(INPUT_PADDING),
+			]
+			.spacing(5)
+			.align_items(Alignment::Center),
+		]
+		.spacing(TITLE_SPACING)
+		.width(Length::Fill);
+
+		if let Some((i, content)) = &self.entry
+			&& let Some(entry) = notes.entries.get(*i)
+		{
+			journal = journal
+				.push(
+					row![
+						text_input(&fl!("date"), &entry.date)
+							.on_input(Message::Date)
+							.padding(INPUT_PADDING)
+							.width(Length::Fixed(100.0)),
+						text_input(&fl!("title"), &entry.title)
+							.on_input(Message::Title)
+							.padding(INPUT_PADDING),
+						button(text(fl!("close")))
+							.on_press(Message::Close)
+							.padding(INPUT_PADDING),
+						button(text(fl!("remove")))
+							.on_press(Message::Delete)
+							.padding(INPUT_PADDING),
+					]
+					.spacing(5)
+					.align_items(Alignment::Center),
+				)
+				.push(
+					text_editor(content)
+						.on_action(Message::EditEntry)
+						.height(Length::Fixed(SECTION_HEIGHT * 2.0)),
+				);
+		}
+
+		journal = journal.push(scrollable(entries).height(Length::Fill));
+
+		column![
+			text(fl!("notes")).size(H2_SIZE),
+			row![sections, journal].spacing(20)
+		]
+		.spacing(TITLE_SPACING)
+		.align_items(Alignment::Center)
+		.padding(10)
+		.into()
+	}
+}
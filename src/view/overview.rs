@@ -1,6 +1,5 @@
 use closure::closure;
 use cofd::{
-	character::Wound,
 	prelude::*,
 	splat::{
 		ability::Ability,
@@ -14,24 +13,29 @@ use cofd::{
 };
 use iced::{
 	theme,
-	widget::{column, pick_list, row, text, text_input, Column, Row},
-	Alignment, Element, Length,
+	widget::{column, container, pick_list, row, text, text_input, tooltip, Column, Row},
+	Alignment, Color, Element, Length,
 };
 
 use crate::{
+	caps::{self, Kind},
 	component::{
-		attributes, info, info::InfoBar, integrity, list, merits, skills, traits, AttributeBar,
-		IntegrityComponent, MeritComponent, SkillsComponent, TraitsComponent,
+		aspirations, attributes, conditions, fuel, health, info, info::InfoBar, integrity, list,
+		merits, skills, tilts, traits, willpower, AspirationsComponent, AttributeBar,
+		ConditionsComponent, FuelComponent, HealthComponent, IntegrityComponent, MeritComponent,
+		SkillsComponent, TiltsComponent, TraitsComponent, WillpowerComponent,
 	},
+	extras::CharacterExtras,
 	fl, i18n,
 	i18n::{Translate, Translated},
-	widget::{
-		dots::{Shape, SheetDots},
-		track::HealthTrack,
-	},
+	modifiers::Breakdown,
+	widget::dots::{Shape, SheetDots},
 	COMPONENT_SPACING, H2_SIZE, H3_SIZE, INPUT_PADDING, MAX_INPUT_WIDTH, TITLE_SPACING,
 };
 
+/// How many of the latest history entries the overview shows.
+const HISTORY_LENGTH: usize = 10;
+
 #[derive(Debug, Clone)]
 pub struct OverviewTab {
 	info_bar: InfoBar,
@@ -40,6 +44,12 @@ pub struct OverviewTab {
 	merit_component: MeritComponent,
 	traits_component: TraitsComponent,
 	integrity_component: IntegrityComponent,
+	health_component: HealthComponent,
+	conditions_component: ConditionsComponent,
+	tilts_component: TiltsComponent,
+	aspirations_component: AspirationsComponent,
+	willpower_component: WillpowerComponent,
+	fuel_component: FuelComponent,
 }
 
 #[derive(Clone)]
@@ -52,15 +62,10 @@ pub enum Message {
 	NewAbility(Ability),
 
 	// CustomAbilityChanged(Ability, String),
-	HealthChanged(Wound),
-	WillpowerChanged(u16),
 	PowerChanged(u16),
-	FuelChanged(u16),
 
 	// IntegrityDamage(SplatType, Wound),
 	// TouchstoneChanged(usize, String),
-	ConditionChanged(usize, String),
-	AspirationChanged(usize, String),
 	SplatThingChanged(usize, String),
 
 	RegaliaChanged(Regalia),
@@ -75,6 +80,12 @@ pub enum Message {
 	MeritComponent(merits::Message),
 	TraitsComponent(traits::Message),
 	IntegrityComponent(integrity::Message),
+	HealthComponent(health::Message),
+	ConditionsComponent(conditions::Message),
+	TiltsComponent(tilts::Message),
+	AspirationsComponent(aspirations::Message),
+	WillpowerComponent(willpower::Message),
+	FuelComponent(fuel::Message),
 }
 
 pub fn vec_changed<T: Default + Clone>(i: usize, val: T, vec: &mut Vec<T>) {
@@ -95,11 +106,22 @@ impl OverviewTab {
 			merit_component: MeritComponent::new(),
 			traits_component: TraitsComponent::new(),
 			integrity_component: IntegrityComponent::new(),
+			health_component: HealthComponent::new(),
+			conditions_component: ConditionsComponent::new(),
+			tilts_component: TiltsComponent::new(),
+			aspirations_component: AspirationsComponent::new(),
+			willpower_component: WillpowerComponent::new(),
+			fuel_component: FuelComponent::new(),
 		}
 	}
 
 	#[allow(clippy::too_many_lines)]
-	pub fn update(&mut self, message: Message, character: &mut Character) {
+	pub fn update(
+		&mut self,
+		message: Message,
+		character: &mut Character,
+		extras: &mut CharacterExtras,
+	) {
 		match message {
 			Message::AbilityValChanged(ability, val) => {
 				if let Some(val_) = character.get_ability_value_mut(&ability) {
@@ -122,25 +144,8 @@ impl OverviewTab {
 				}
 			}
 
-			Message::HealthChanged(wound) => character.health_mut().poke(&wound),
-			Message::WillpowerChanged(willpower) => character.willpower = willpower,
 			Message::PowerChanged(power) => character.power = power,
-			Message::FuelChanged(fuel) => character.fuel = fuel,
 
-			Message::ConditionChanged(i, val) => {
-				if val.is_empty() {
-					character.conditions.remove(i);
-				} else {
-					vec_changed(i, val, &mut character.conditions);
-				}
-			}
-			Message::AspirationChanged(i, val) => {
-				if val.is_empty() {
-					character.aspirations.remove(i);
-				} else {
-					vec_changed(i, val, &mut character.aspirations);
-				}
-			}
 			Message::SplatThingChanged(i, val) => match &mut character.splat {
 				Splat::Changeling(.., data) => {
 					if val.is_empty() {
@@ -199,49 +204,65 @@ impl OverviewTab {
 
 			Message::InfoBar(message) => self.info_bar.update(message, character),
 			Message::AttributeBar(message) => self.attribute_bar.update(message, character),
-			Message::SkillComponent(message) => self.skills_component.update(message, character),
+			Message::SkillComponent(message) => {
+				self.skills_component.update(message, character, extras);
+			}
 			Message::MeritComponent(message) => self.merit_component.update(message, character),
-			Message::TraitsComponent(message) => self.traits_component.update(message, character),
+			Message::TraitsComponent(message) => {
+				self.traits_component.update(message, character, extras);
+			}
 			Message::IntegrityComponent(message) => {
 				self.integrity_component.update(message, character);
 			}
+			Message::HealthComponent(message) => {
+				self.health_component.update(message, character, extras);
+			}
+			Message::ConditionsComponent(message) => {
+				self.conditions_component.update(message, character);
+			}
+			Message::TiltsComponent(message) => {
+				self.tilts_component.update(message, &mut extras.tilts);
+			}
+			Message::AspirationsComponent(message) => {
+				self.aspirations_component
+					.update(message, character, extras);
+			}
+			Message::WillpowerComponent(message) => {
+				self.willpower_component.update(message, character, extras);
+			}
+			Message::FuelComponent(message) => {
+				self.fuel_component.update(message, character, extras);
+			}
 		}
 	}
 
 	#[allow(clippy::too_many_lines)]
-	pub fn view(&self, character: &Character) -> Element<Message> {
-		let health = {
-			let track = HealthTrack::new(
-				character.health().clone(),
-				character.max_health() as usize,
-				Message::HealthChanged,
-			);
-
-			let wp = character.wound_penalty();
-			let mut label = fl!("health");
-
-			if wp > 0 {
-				label += &format!(" (-{wp})");
-			}
-			column![text(label).size(H3_SIZE), track]
-				.spacing(TITLE_SPACING)
-				.align_items(Alignment::Center)
-		};
-
-		let willpower = {
-			let dots = SheetDots::new(
-				character.willpower,
-				0,
-				character.max_willpower(),
-				Shape::Dots,
-				None,
-				Message::WillpowerChanged,
-			);
+	pub fn view<'a>(
+		&'a self,
+		character: &'a Character,
+		extras: &'a CharacterExtras,
+	) -> Element<'a, Message> {
+		// Working out where modifiers come from is costly, so it's done once for every trait.
+		let breakdown = Breakdown::new(character);
+
+		let health = self
+			.health_component
+			.view(character, extras, &breakdown)
+			.map(Message::HealthComponent);
+
+		let tilts = column![
+			text(fl!("tilts")).size(H3_SIZE),
+			self.tilts_component
+				.view(&extras.tilts)
+				.map(Message::TiltsComponent)
+		]
+		.spacing(TITLE_SPACING)
+		.align_items(Alignment::Center);
 
-			column![text(fl!("willpower")).size(H3_SIZE), dots]
-				.spacing(TITLE_SPACING)
-				.align_items(Alignment::Center)
-		};
+		let willpower = self
+			.willpower_component
+			.view(character, extras, &breakdown)
+			.map(Message::WillpowerComponent);
 
 		let st = if let Some(st) = character.splat.supernatural_tolerance() {
 			let dots = SheetDots::new(
@@ -253,62 +274,48 @@ impl OverviewTab {
 				Message::PowerChanged,
 			);
 
-			column![text(st.translated()).size(H3_SIZE), dots]
+			let mut col = column![text(st.translated()).size(H3_SIZE), dots]
 				.spacing(TITLE_SPACING)
-				.align_items(Alignment::Center)
-		} else {
-			column![]
-		};
+				.align_items(Alignment::Center);
 
-		let fuel = if let Some(fuel) = character.splat.fuel() {
-			let boxes = SheetDots::new(
-				character.fuel,
-				0,
-				character.max_fuel(),
-				Shape::Boxes,
-				Some(10),
-				Message::FuelChanged,
-			);
+			let over = caps::over(character);
+			if !over.is_empty() {
+				let traits = over
+					.iter()
+					.map(|(name, max)| format!("{name} ({max})"))
+					.collect::<Vec<_>>()
+					.join(", ");
 
-			column![text(fuel.translated()).size(H3_SIZE), boxes]
-				.spacing(TITLE_SPACING)
-				.align_items(Alignment::Center)
+				col = col.push(
+					text(fl!("over-cap", traits = traits))
+						.style(theme::Text::Color(Color::from_rgb(0.8, 0.0, 0.0))),
+				);
+			}
+
+			col
 		} else {
 			column![]
 		};
 
+		let fuel = self
+			.fuel_component
+			.view(character, extras)
+			.map(Message::FuelComponent);
+
 		let integrity = self
 			.integrity_component
 			.view(character)
 			.map(Message::IntegrityComponent);
 
-		let conditions = list(
-			fl!("conditions"),
-			Some(character.conditions.len() + 1),
-			None,
-			character.conditions.clone(),
-			|i, val| {
-				text_input("", &val.unwrap_or_default())
-					.on_input(move |val| Message::ConditionChanged(i, val))
-					.padding(INPUT_PADDING)
-					.into()
-			},
-		);
-		// .max_width(MAX_INPUT_WIDTH);
-
-		let aspirations = list(
-			fl!("aspirations"),
-			Some(character.aspirations.len() + 1),
-			Some(3),
-			character.aspirations.clone(),
-			|i, val| {
-				text_input("", &val.unwrap_or_default())
-					.on_input(move |val| Message::AspirationChanged(i, val))
-					.padding(INPUT_PADDING)
-					.into()
-			},
-		);
-		// .max_width(MAX_INPUT_WIDTH);
+		let conditions = self
+			.conditions_component
+			.view(character)
+			.map(Message::ConditionsComponent);
+
+		let aspirations = self
+			.aspirations_component
+			.view(extras)
+			.map(Message::AspirationsComponent);
 
 		let obsessions = if let Splat::Mage(.., data) = &character.splat {
 			column![list(
@@ -415,7 +422,7 @@ impl OverviewTab {
 			.map(Message::MeritComponent);
 		let traits = self
 			.traits_component
-			.view(character)
+			.view(character, extras, &breakdown)
 			.map(Message::TraitsComponent);
 
 		let regalia = if let Splat::Changeling(data) = &character.splat {
@@ -545,6 +552,7 @@ impl OverviewTab {
 
 		let mut col2 = Column::new()
 			.push(health)
+			.push(tilts)
 			.push(willpower)
 			.spacing(COMPONENT_SPACING)
 			.align_items(Alignment::Center)
@@ -608,18 +616,30 @@ impl OverviewTab {
 			}
 		}
 
+		if !extras.history.is_empty() {
+			let mut history = column![text(fl!("history")).size(H3_SIZE)]
+				.spacing(TITLE_SPACING)
+				.align_items(Alignment::Center);
+
+			for event in extras.history.iter().rev().take(HISTORY_LENGTH) {
+				history = history.push(text(event.translated()));
+			}
+
+			col2 = col2.push(history);
+		}
+
 		column![
 			column![
 				self.info_bar.view(character).map(Message::InfoBar),
 				self.attribute_bar
-					.view(character)
+					.view(character, &breakdown)
 					.map(Message::AttributeBar)
 			]
 			.align_items(Alignment::Center)
 			.width(Length::Fill),
 			row![
 				self.skills_component
-					.view(character)
+					.view(character, extras, &breakdown)
 					.map(Message::SkillComponent),
 				column![
 					text("Other Traits".to_uppercase()).size(H2_SIZE),
@@ -643,6 +663,7 @@ impl OverviewTab {
 		.into()
 	}
 
+	#[allow(clippy::too_many_lines)]
 	fn abilities(&self, character: &Character) -> Element<Message> {
 		let mut col = Column::new().spacing(3);
 
@@ -651,13 +672,27 @@ impl OverviewTab {
 				for ability in abilities {
 					let val = character.get_ability_value(&ability).unwrap_or(&0);
 
-					col = col.push(
-						Row::new()
-							.push(text(ability.translated()).width(Length::Fill))
-							.push(SheetDots::new(*val, 0, 5, Shape::Dots, None, move |val| {
-								Message::AbilityValChanged(ability.clone(), val)
-							})),
-					);
+					let name: Element<Message> =
+						if let Some(rules) = merits::rules(ability.name(), &[]) {
+							container(tooltip(
+								text(ability.translated()),
+								container(rules).style(theme::Container::Box),
+								tooltip::Position::Bottom,
+							))
+							.width(Length::Fill)
+							.into()
+						} else {
+							text(ability.translated()).width(Length::Fill).into()
+						};
+
+					col = col.push(Row::new().push(name).push(SheetDots::new(
+						*val,
+						0,
+						caps::of(character, Kind::Ability),
+						Shape::Dots,
+						Some(caps::ROW),
+						move |val| Message::AbilityValChanged(ability.clone(), val),
+					)));
 				}
 			} else {
 				let mut vec = abilities.clone();
@@ -699,13 +734,22 @@ impl OverviewTab {
 					let dots = SheetDots::new(
 						*val,
 						0,
-						5,
+						caps::of(character, Kind::Ability),
 						Shape::Dots,
-						None,
+						Some(caps::ROW),
 						closure!(clone ability, |val| Message::AbilityValChanged(ability.clone(), val)),
 					);
 
-					col = col.push(row![item, dots]);
+					let row = row![item, dots];
+					col = col.push(if let Some(rules) = merits::rules(ability.name(), &[]) {
+						Element::from(tooltip(
+							row,
+							container(rules).style(theme::Container::Box),
+							tooltip::Position::Bottom,
+						))
+					} else {
+						row.into()
+					});
 				}
 
 				col = col.push(
@@ -1,21 +1,41 @@
 use cofd::character::Character;
 use iced::{
-	widget::{button, column, row},
-	Element, Length,
+	widget::{button, checkbox, column, row, text, text_input, Column},
+	Alignment, Element, Length,
 };
 
 use super::*;
+use crate::{
+	catalog::conditions,
+	extras::CharacterExtras,
+	fl,
+	i18n::Translate,
+	session::{self, Awards, End},
+	willpower::Spend,
+	H3_SIZE, INPUT_PADDING, TITLE_SPACING,
+};
 
 #[derive(Debug, Clone)]
 pub enum Tab {
 	Overview(overview::OverviewTab),
 	Equipment(equipment::EquipmentTab),
 	SplatExtras(splat_extras::SplatExtrasTab),
+	Notes(notes::NotesTab),
+	Dice,
 }
 
 #[derive(Debug, Clone)]
 pub struct SheetView {
 	tab: Tab,
+	dice: dice::DiceTab,
+	/// The Beats being claimed for a scene, chapter or story that's ending.
+	ending: Option<(End, Awards)>,
+}
+
+/// A roll made from the sheet.
+pub struct Rolled {
+	/// The roster character it's up against and the Willpower spend of theirs it uses up.
+	pub opponent: Option<(usize, Spend)>,
 }
 
 #[derive(Clone)]
@@ -23,6 +43,8 @@ pub enum Message {
 	OverviewTab(overview::Message),
 	EquipmentTab(equipment::Message),
 	SplatExtras(splat_extras::Message),
+	Notes(notes::Message),
+	Dice(dice::Message),
 
 	Back,
 	Save,
@@ -30,6 +52,15 @@ pub enum Message {
 	SelectOverview,
 	SelectEquipment,
 	SelectSplatExtras,
+	SelectNotes,
+	SelectDice,
+
+	End(End),
+	AwardAspiration(usize, bool),
+	AwardCondition(usize, bool),
+	DramaticFailures(u16),
+	ConfirmEnd,
+	CancelEnd,
 }
 
 impl Default for SheetView {
@@ -42,19 +73,30 @@ impl SheetView {
 	pub fn new() -> Self {
 		Self {
 			tab: Tab::Overview(overview::OverviewTab::new()),
+			dice: dice::DiceTab::new(),
+			ending: None,
 		}
 	}
 
-	pub fn update(&mut self, message: Message, character: &mut Character) {
+	pub fn update(
+		&mut self,
+		message: Message,
+		character: &mut Character,
+		extras: &mut CharacterExtras,
+	) {
 		match message {
 			Message::OverviewTab(message) => {
 				if let Tab::Overview(view) = &mut self.tab {
-					view.update(message, character);
+					view.update(message, character, extras);
 				}
 			}
+			Message::EquipmentTab(equipment::Message::Roll(request)) => {
+				self.dice.update(dice::Message::Roll(request));
+				self.tab = Tab::Dice;
+			}
 			Message::EquipmentTab(message) => {
 				if let Tab::Equipment(view) = &mut self.tab {
-					view.update(message, character);
+					view.update(message, character, extras);
 				}
 			}
 			Message::SplatExtras(message) => {
@@ -62,36 +104,168 @@ impl SheetView {
 					view.update(message, character);
 				}
 			}
+			Message::Notes(message) => {
+				if let Tab::Notes(view) = &mut self.tab {
+					view.update(message, extras);
+				}
+			}
+
+			Message::Dice(message) => self.dice.update(message),
 
 			Message::SelectOverview => self.tab = Tab::Overview(overview::OverviewTab::new()),
 			Message::SelectEquipment => self.tab = Tab::Equipment(equipment::EquipmentTab::new()),
 			Message::SelectSplatExtras => {
 				self.tab = Tab::SplatExtras(splat_extras::SplatExtrasTab::new());
 			}
+			Message::SelectNotes => self.tab = Tab::Notes(notes::NotesTab::new(&extras.notes)),
+			Message::SelectDice => self.tab = Tab::Dice,
+
+			Message::End(end) => self.ending = Some((end, Awards::default())),
+			Message::AwardAspiration(i, award) => {
+				if let Some((_, awards)) = &mut self.ending {
+					toggle(&mut awards.aspirations, i, award);
+				}
+			}
+			Message::AwardCondition(i, award) => {
+				if let Some((_, awards)) = &mut self.ending {
+					toggle(&mut awards.conditions, i, award);
+				}
+			}
+			Message::DramaticFailures(count) => {
+				if let Some((_, awards)) = &mut self.ending {
+					awards.dramatic_failures = count;
+				}
+			}
+			Message::ConfirmEnd => {
+				if let Some((end, awards)) = self.ending.take() {
+					session::end(character, extras, end, &awards);
+				}
+			}
+			Message::CancelEnd => self.ending = None,
 			_ => {}
 		}
 	}
 
+	/// Whether the message rolls dice, and who against.
+	pub fn rolled(&self, message: &Message) -> Option<Rolled> {
+		match message {
+			Message::Dice(dice::Message::Roll(request)) => Some(Rolled {
+				opponent: self.dice.opponent(request),
+			}),
+			Message::EquipmentTab(
+				equipment::Message::Roll(_) | equipment::Message::RollPurchase(_),
+			) => Some(Rolled { opponent: None }),
+			_ => None,
+		}
+	}
+
 	#[allow(clippy::too_many_lines)]
-	pub fn view(&self, character: &Character) -> Element<Message> {
+	pub fn view<'a>(
+		&'a self,
+		character: &'a Character,
+		extras: &'a CharacterExtras,
+		roster: &'a [Character],
+		roster_extras: &'a [CharacterExtras],
+	) -> Element<'a, Message> {
 		let tab: Element<Message> = match &self.tab {
-			Tab::Overview(view) => view.view(character).map(Message::OverviewTab),
-			Tab::Equipment(view) => view.view(character).map(Message::EquipmentTab),
+			Tab::Overview(view) => view.view(character, extras).map(Message::OverviewTab),
+			Tab::Equipment(view) => view.view(character, extras).map(Message::EquipmentTab),
 			Tab::SplatExtras(view) => view.view(character).map(Message::SplatExtras),
+			Tab::Notes(view) => view.view(extras).map(Message::Notes),
+			Tab::Dice => self
+				.dice
+				.view(character, extras, roster, roster_extras)
+				.map(Message::Dice),
 		};
 
-		column![
-			row![
-				button("Back").on_press(Message::Back),
-				button("Save").on_press(Message::Save),
-				button("Home").on_press(Message::SelectOverview),
-				button("Equipment").on_press(Message::SelectEquipment),
-				button("Splat").on_press(Message::SelectSplatExtras),
-			],
-			tab
-		]
+		let mut col = column![row![
+			button("Back").on_press(Message::Back),
+			button("Save").on_press(Message::Save),
+			button("Home").on_press(Message::SelectOverview),
+			button("Equipment").on_press(Message::SelectEquipment),
+			button("Splat").on_press(Message::SelectSplatExtras),
+			button("Notes").on_press(Message::SelectNotes),
+			button("Dice").on_press(Message::SelectDice),
+			button(text(fl!("end-scene"))).on_press(Message::End(End::Scene)),
+			button(text(fl!("end-chapter"))).on_press(Message::End(End::Chapter)),
+			button(text(fl!("end-story"))).on_press(Message::End(End::Story)),
+		]]
 		.width(Length::Fill)
-		.spacing(1)
+		.spacing(1);
+
+		if let Some((end, awards)) = &self.ending {
+			col = col.push(Self::ending(*end, awards, character, extras));
+		}
+
+		col.push(tab).into()
+	}
+
+	/// Asks which Beats were earned before wrapping up.
+	fn ending<'a>(
+		end: End,
+		awards: &Awards,
+		character: &'a Character,
+		extras: &'a CharacterExtras,
+	) -> Element<'a, Message> {
+		let mut col = Column::new()
+			.spacing(TITLE_SPACING)
+			.align_items(Alignment::Center)
+			.width(Length::Fill)
+			.push(text(fl!("end-of", end = end.translated())).size(H3_SIZE));
+
+		for (i, aspiration) in extras.aspirations.iter().enumerate() {
+			if aspiration.is_active() {
+				col = col.push(
+					checkbox(
+						fl!("award-aspiration", aspiration = aspiration.text.as_str()),
+						awards.aspirations.contains(&i),
+					)
+					.on_toggle(move |val| Message::AwardAspiration(i, val)),
+				);
+			}
+		}
+
+		for (i, condition) in character.conditions.iter().enumerate() {
+			let name =
+				conditions::get(condition).map_or_else(|| condition.clone(), Translate::translated);
+
+			col = col.push(
+				checkbox(
+					fl!("award-condition", condition = name),
+					awards.conditions.contains(&i),
+				)
+				.on_toggle(move |val| Message::AwardCondition(i, val)),
+			);
+		}
+
+		col.push(
+			row![
+				text(fl!("dramatic-failures")),
+				text_input("", &awards.dramatic_failures.to_string())
+					.on_input(|val| Message::DramaticFailures(val.parse().unwrap_or(0)))
+					.padding(INPUT_PADDING)
+					.width(Length::Fixed(40.0)),
+			]
+			.spacing(5)
+			.align_items(Alignment::Center),
+		)
+		.push(
+			row![
+				button(text(fl!("confirm"))).on_press(Message::ConfirmEnd),
+				button(text(fl!("cancel"))).on_press(Message::CancelEnd),
+			]
+			.spacing(5),
+		)
 		.into()
 	}
 }
+
+fn toggle(vec: &mut Vec<usize>, i: usize, on: bool) {
+	if on {
+		if !vec.contains(&i) {
+			vec.push(i);
+		}
+	} else {
+		vec.retain(|val| *val != i);
+	}
+}
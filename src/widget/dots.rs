@@ -4,10 +4,16 @@ use iced::{
 	advanced::{
 		layout::{self, Layout, Limits, Node},
 		renderer,
-		widget::{self, Widget},
+		widget::{
+			self,
+			operation::{self, Operation},
+			tree, Widget,
+		},
 		Clipboard, Shell,
 	},
-	event, mouse, touch,
+	event,
+	keyboard::{self, key::Named, Key},
+	mouse, touch,
 	widget::text,
 	Background, Border, Color, Element, Length, Point, Rectangle, Size, Theme,
 };
@@ -30,6 +36,26 @@ where
 	// child: Column<'a, Message, Theme>,
 }
 
+/// Dots can be focused by clicking them or with Tab, and then changed from the keyboard.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+	is_focused: bool,
+}
+
+impl operation::Focusable for State {
+	fn is_focused(&self) -> bool {
+		self.is_focused
+	}
+
+	fn focus(&mut self) {
+		self.is_focused = true;
+	}
+
+	fn unfocus(&mut self) {
+		self.is_focused = false;
+	}
+}
+
 impl<'a, Message, Theme> SheetDots<'a, Message, Theme>
 where
 	Message: Clone,
@@ -83,6 +109,20 @@ where
 		self.spacing = spacing;
 		self
 	}
+
+	/// The value a key press changes the dots to, if it's one the dots react to and the value
+	/// isn't already at the end of the range.
+	fn key_value(&self, key: &Key<&str>) -> Option<u16> {
+		match key {
+			Key::Named(Named::ArrowRight | Named::ArrowUp) | Key::Character("+" | "=") => {
+				(self.value < self.max).then(|| self.value + 1)
+			}
+			Key::Named(Named::ArrowLeft | Named::ArrowDown) | Key::Character("-") => {
+				(self.value > self.min).then(|| self.value - 1)
+			}
+			_ => None,
+		}
+	}
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -92,6 +132,14 @@ where
 	Renderer: renderer::Renderer,
 	Theme: StyleSheet + text::StyleSheet + 'static,
 {
+	fn tag(&self) -> tree::Tag {
+		tree::Tag::of::<State>()
+	}
+
+	fn state(&self) -> tree::State {
+		tree::State::new(State::default())
+	}
+
 	fn size(&self) -> Size<Length> {
 		Size {
 			width: self.width,
@@ -159,7 +207,7 @@ where
 
 	fn draw(
 		&self,
-		_state: &widget::Tree,
+		tree: &widget::Tree,
 		renderer: &mut Renderer,
 		theme: &Theme,
 		_style: &renderer::Style,
@@ -213,11 +261,38 @@ where
 				);
 			}
 		}
+
+		if tree.state.downcast_ref::<State>().is_focused {
+			let focused = theme.focused(self.style);
+
+			renderer.fill_quad(
+				renderer::Quad {
+					bounds: layout_bounds.expand(focused.border_width),
+					border: Border {
+						radius: 2.0.into(),
+						width: focused.border_width,
+						color: focused.border_color,
+					},
+					..Default::default()
+				},
+				focused.background,
+			);
+		}
+	}
+
+	fn operate(
+		&self,
+		tree: &mut widget::Tree,
+		_layout: Layout<'_>,
+		_renderer: &Renderer,
+		operation: &mut dyn Operation<Message>,
+	) {
+		operation.focusable(tree.state.downcast_mut::<State>(), None);
 	}
 
 	fn on_event(
 		&mut self,
-		_state: &mut widget::Tree,
+		tree: &mut widget::Tree,
 		event: event::Event,
 		layout: Layout<'_>,
 		cursor: mouse::Cursor,
@@ -226,11 +301,15 @@ where
 		shell: &mut Shell<'_, Message>,
 		_viewport: &Rectangle,
 	) -> event::Status {
+		let state = tree.state.downcast_mut::<State>();
+
 		match event {
 			event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
 			| event::Event::Touch(touch::Event::FingerPressed { .. }) => {
 				for (i, layout) in layout.children().enumerate() {
 					if cursor.position_over(layout.bounds()).is_some() {
+						state.is_focused = true;
+
 						let i = if self.value as usize == i + 1 {
 							i
 						} else {
@@ -244,6 +323,19 @@ where
 						return event::Status::Captured;
 					}
 				}
+
+				state.is_focused = false;
+			}
+			event::Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if state.is_focused => {
+				if key == Key::Named(Named::Escape) {
+					state.is_focused = false;
+					return event::Status::Captured;
+				}
+
+				if let Some(value) = self.key_value(&key.as_ref()) {
+					shell.publish((self.on_click)(value));
+					return event::Status::Captured;
+				}
 			}
 			_ => {}
 		}
@@ -299,6 +391,9 @@ pub trait StyleSheet {
 	fn active(&self, style: Self::Style) -> Appearance;
 
 	fn hovered(&self, style: Self::Style) -> Appearance;
+
+	/// The outline drawn around the dots while they have keyboard focus.
+	fn focused(&self, style: Self::Style) -> Appearance;
 }
 
 impl StyleSheet for Theme {
@@ -326,6 +421,17 @@ impl StyleSheet for Theme {
 			..active
 		}
 	}
+
+	fn focused(&self, style: Self::Style) -> Appearance {
+		let active = self.active(style);
+		let palette = self.extended_palette();
+
+		Appearance {
+			border_width: 2.0,
+			border_color: palette.primary.base.color,
+			..active
+		}
+	}
 }
 
 pub enum Shape {
@@ -339,3 +445,109 @@ pub enum Axis {
 	#[default]
 	Horizontal,
 }
+
+#[cfg(test)]
+mod tests {
+	use iced::{
+		event,
+		keyboard::{key::Named, Key},
+		Theme,
+	};
+
+	use super::{Shape, SheetDots};
+	use crate::widget::harness::{click, key, send, tree};
+
+	type Dots = SheetDots<'static, u16, Theme>;
+
+	fn dots(value: u16) -> Dots {
+		SheetDots::new(value, 1, 5, Shape::Dots, None, |val| val)
+	}
+
+	#[test]
+	fn ignores_keys_until_focused() {
+		let mut dots = dots(3);
+		let mut tree = tree(&dots);
+
+		let (statuses, messages) = send(
+			&mut dots,
+			&mut tree,
+			vec![key(Key::Named(Named::ArrowRight))],
+		);
+
+		assert_eq!(statuses, vec![event::Status::Ignored]);
+		assert!(messages.is_empty());
+	}
+
+	#[test]
+	fn arrows_and_signs_change_the_value() {
+		let mut widget = dots(3);
+		let mut tree = tree(&widget);
+		let mut values = Vec::new();
+
+		for input in [
+			click(),
+			key(Key::Named(Named::ArrowRight)),
+			key(Key::Named(Named::ArrowUp)),
+			key(Key::Character("+".into())),
+			key(Key::Character("+".into())),
+			key(Key::Character("+".into())),
+			key(Key::Named(Named::ArrowLeft)),
+			key(Key::Named(Named::ArrowDown)),
+			key(Key::Character("-".into())),
+		] {
+			let (_, messages) = send(&mut widget, &mut tree, vec![input]);
+
+			// Rebuild the dots with what they published, as the sheet would.
+			if let Some(&value) = messages.last() {
+				widget = dots(value);
+			}
+			values.extend(messages);
+		}
+
+		// The click on the first dot sets the value to 1, and nothing goes past 5.
+		assert_eq!(values, vec![1, 2, 3, 4, 5, 4, 3, 2]);
+	}
+
+	#[test]
+	fn stays_within_range() {
+		let mut low = dots(1);
+		let mut low_tree = tree(&low);
+		let (statuses, messages) = send(
+			&mut low,
+			&mut low_tree,
+			vec![click(), key(Key::Named(Named::ArrowLeft))],
+		);
+
+		assert_eq!(statuses[1], event::Status::Ignored);
+		assert!(messages.is_empty());
+
+		let mut high = dots(5);
+		let mut high_tree = tree(&high);
+		let (_, messages) = send(
+			&mut high,
+			&mut high_tree,
+			vec![click(), key(Key::Named(Named::ArrowRight))],
+		);
+
+		assert_eq!(messages, vec![1]);
+	}
+
+	#[test]
+	fn escape_gives_up_focus() {
+		let mut dots = dots(3);
+		let mut tree = tree(&dots);
+
+		let (statuses, messages) = send(
+			&mut dots,
+			&mut tree,
+			vec![
+				click(),
+				key(Key::Named(Named::Escape)),
+				key(Key::Named(Named::ArrowRight)),
+			],
+		);
+
+		assert_eq!(statuses[2], event::Status::Ignored);
+		assert_eq!(messages, vec![1]);
+	}
+}
@@ -0,0 +1,71 @@
+//! Drives widgets with events in tests, without a window or renderer.
+
+use iced::{
+	advanced::{
+		clipboard,
+		layout::{Layout, Limits},
+		renderer::Null,
+		widget::{Tree, Widget},
+		Shell,
+	},
+	event::{self, Event},
+	keyboard::{self, Key, Location, Modifiers},
+	mouse, Point, Rectangle, Size, Theme,
+};
+
+pub type Input = (Event, mouse::Cursor);
+
+/// A key press with nothing under the mouse.
+pub fn key(key: Key) -> Input {
+	(
+		Event::Keyboard(keyboard::Event::KeyPressed {
+			key,
+			location: Location::Standard,
+			modifiers: Modifiers::default(),
+			text: None,
+		}),
+		mouse::Cursor::Unavailable,
+	)
+}
+
+/// A click on the top left corner, where the first dot or box is.
+pub fn click() -> Input {
+	(
+		Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+		mouse::Cursor::Available(Point::new(1.0, 1.0)),
+	)
+}
+
+pub fn tree<M, W: Widget<M, Theme, Null> + 'static>(widget: &W) -> Tree {
+	Tree::new(widget as &dyn Widget<M, Theme, Null>)
+}
+
+/// Sends the events to the widget, returning what it did with each and the messages it
+/// published.
+pub fn send<M, W: Widget<M, Theme, Null>>(
+	widget: &mut W,
+	tree: &mut Tree,
+	events: Vec<Input>,
+) -> (Vec<event::Status>, Vec<M>) {
+	let node = widget.layout(tree, &Null, &Limits::NONE);
+	let mut messages = Vec::new();
+	let mut shell = Shell::new(&mut messages);
+
+	let statuses = events
+		.into_iter()
+		.map(|(event, cursor)| {
+			widget.on_event(
+				tree,
+				event,
+				Layout::new(&node),
+				cursor,
+				&Null,
+				&mut clipboard::Null,
+				&mut shell,
+				&Rectangle::with_size(Size::INFINITY),
+			)
+		})
+		.collect();
+
+	(statuses, messages)
+}
@@ -1,2 +1,5 @@
 pub mod dots;
 pub mod track;
+
+#[cfg(test)]
+mod harness;
@@ -5,10 +5,16 @@ use iced::{
 	advanced::{
 		layout::{self, Layout, Node},
 		renderer,
-		widget::{self, Widget},
+		widget::{
+			self,
+			operation::{self, Operation},
+			tree, Widget,
+		},
 		Clipboard, Shell,
 	},
-	event, mouse, touch,
+	event,
+	keyboard::{self, key::Named, Key},
+	mouse, touch,
 	widget::text,
 	Background, Border, Color, Element, Length, Point, Rectangle, Size, Theme,
 };
@@ -26,6 +32,27 @@ where
 	style: <Theme as StyleSheet>::Style,
 }
 
+/// While focused, the arrow keys move between boxes and Enter or Space pokes the current one.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+	is_focused: bool,
+	cursor: usize,
+}
+
+impl operation::Focusable for State {
+	fn is_focused(&self) -> bool {
+		self.is_focused
+	}
+
+	fn focus(&mut self) {
+		self.is_focused = true;
+	}
+
+	fn unfocus(&mut self) {
+		self.is_focused = false;
+	}
+}
+
 impl<'a, Message, Theme> HealthTrack<'a, Message, Theme>
 where
 	Message: Clone,
@@ -51,6 +78,22 @@ where
 			style: Default::default(),
 		}
 	}
+
+	/// Where a key press moves the cursor to, if it's an arrow key.
+	fn move_cursor(&self, cursor: usize, key: &Key<&str>) -> Option<usize> {
+		let step = self.per_row_count.unwrap_or(self.max);
+		let last = self.max.checked_sub(1)?;
+
+		match key {
+			Key::Named(Named::ArrowRight) => Some((cursor + 1).min(last)),
+			Key::Named(Named::ArrowLeft) => Some(cursor.saturating_sub(1)),
+			Key::Named(Named::ArrowDown) => Some((cursor + step).min(last)),
+			Key::Named(Named::ArrowUp) => Some(cursor.saturating_sub(step)),
+			Key::Named(Named::Home) => Some(0),
+			Key::Named(Named::End) => Some(last),
+			_ => None,
+		}
+	}
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -60,6 +103,14 @@ where
 	Renderer: renderer::Renderer,
 	Theme: StyleSheet + text::StyleSheet,
 {
+	fn tag(&self) -> tree::Tag {
+		tree::Tag::of::<State>()
+	}
+
+	fn state(&self) -> tree::State {
+		tree::State::new(State::default())
+	}
+
 	fn size(&self) -> Size<Length> {
 		Size {
 			width: Length::Shrink,
@@ -107,7 +158,7 @@ where
 
 	fn draw(
 		&self,
-		_state: &widget::Tree,
+		tree: &widget::Tree,
 		renderer: &mut Renderer,
 		theme: &Theme,
 		_style: &renderer::Style,
@@ -115,9 +166,15 @@ where
 		_cursor: mouse::Cursor,
 		_viewport: &Rectangle,
 	) {
+		let state = tree.state.downcast_ref::<State>();
+
 		for (i, layout) in layout.children().enumerate() {
 			let bounds = layout.bounds();
-			let custom_style = theme.active(self.style);
+			let custom_style = if state.is_focused && state.cursor == i {
+				theme.focused(self.style)
+			} else {
+				theme.active(self.style)
+			};
 
 			let wound = self.damage.get_i(i);
 			renderer.fill_quad(
@@ -141,9 +198,19 @@ where
 		}
 	}
 
+	fn operate(
+		&self,
+		tree: &mut widget::Tree,
+		_layout: Layout<'_>,
+		_renderer: &Renderer,
+		operation: &mut dyn Operation<Message>,
+	) {
+		operation.focusable(tree.state.downcast_mut::<State>(), None);
+	}
+
 	fn on_event(
 		&mut self,
-		_state: &mut widget::Tree,
+		tree: &mut widget::Tree,
 		event: event::Event,
 		layout: Layout<'_>,
 		cursor: mouse::Cursor,
@@ -152,17 +219,42 @@ where
 		shell: &mut Shell<'_, Message>,
 		_viewport: &Rectangle,
 	) -> event::Status {
+		let state = tree.state.downcast_mut::<State>();
+
 		match event {
 			event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
 			| event::Event::Touch(touch::Event::FingerPressed { .. }) => {
 				for (i, layout) in layout.children().enumerate() {
 					if cursor.position_over(layout.bounds()).is_some() {
+						state.is_focused = true;
+						state.cursor = i;
+
 						let wound = self.damage.get_i(i);
 						shell.publish((self.on_click)(wound));
 
 						return event::Status::Captured;
 					}
 				}
+
+				state.is_focused = false;
+			}
+			event::Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if state.is_focused => {
+				// The track may have shrunk since the cursor was last moved.
+				state.cursor = state.cursor.min(self.max.saturating_sub(1));
+
+				match key.as_ref() {
+					Key::Named(Named::Escape) => state.is_focused = false,
+					Key::Named(Named::Enter | Named::Space) if self.max > 0 => {
+						let wound = self.damage.get_i(state.cursor);
+						shell.publish((self.on_click)(wound));
+					}
+					key => match self.move_cursor(state.cursor, &key) {
+						Some(cursor) => state.cursor = cursor,
+						None => return event::Status::Ignored,
+					},
+				}
+
+				return event::Status::Captured;
 			}
 			_ => {}
 		}
@@ -218,6 +310,9 @@ pub trait StyleSheet {
 	fn active(&self, style: Self::Style) -> Appearance;
 
 	fn hovered(&self, style: Self::Style) -> Appearance;
+
+	/// The box the keyboard cursor is on, while the track has focus.
+	fn focused(&self, style: Self::Style) -> Appearance;
 }
 
 impl StyleSheet for Theme {
@@ -245,4 +340,131 @@ impl StyleSheet for Theme {
 			..active
 		}
 	}
+
+	fn focused(&self, style: Self::Style) -> Appearance {
+		let active = self.active(style);
+		let palette = self.extended_palette();
+
+		Appearance {
+			border_width: 3.0,
+			border_color: palette.primary.base.color,
+			..active
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cofd::character::{Damage, Wound};
+	use iced::{
+		advanced::widget::Tree,
+		event,
+		keyboard::{key::Named, Key},
+		Theme,
+	};
+
+	use super::{HealthTrack, State};
+	use crate::widget::harness::{click, key, send, tree};
+
+	type Track = HealthTrack<'static, Wound, Theme>;
+
+	fn track() -> Track {
+		HealthTrack::new(Damage::default(), 7, |wound| wound)
+	}
+
+	fn cursor(tree: &Tree) -> usize {
+		tree.state.downcast_ref::<State>().cursor
+	}
+
+	#[test]
+	fn ignores_keys_until_focused() {
+		let mut track = track();
+		let mut tree = tree(&track);
+
+		let (statuses, messages) = send(&mut track, &mut tree, vec![key(Key::Named(Named::Enter))]);
+
+		assert_eq!(statuses, vec![event::Status::Ignored]);
+		assert!(messages.is_empty());
+	}
+
+	#[test]
+	fn arrows_move_between_boxes() {
+		let mut track = track();
+		let mut tree = tree(&track);
+
+		send(
+			&mut track,
+			&mut tree,
+			vec![
+				click(),
+				key(Key::Named(Named::ArrowRight)),
+				key(Key::Named(Named::ArrowRight)),
+			],
+		);
+		assert_eq!(cursor(&tree), 2);
+
+		send(
+			&mut track,
+			&mut tree,
+			vec![key(Key::Named(Named::ArrowLeft))],
+		);
+		assert_eq!(cursor(&tree), 1);
+
+		// All seven boxes fit in one row, so down goes to the last one.
+		send(
+			&mut track,
+			&mut tree,
+			vec![key(Key::Named(Named::ArrowDown))],
+		);
+		assert_eq!(cursor(&tree), 6);
+
+		send(
+			&mut track,
+			&mut tree,
+			vec![key(Key::Named(Named::ArrowRight))],
+		);
+		assert_eq!(cursor(&tree), 6);
+
+		send(&mut track, &mut tree, vec![key(Key::Named(Named::Home))]);
+		assert_eq!(cursor(&tree), 0);
+	}
+
+	#[test]
+	fn enter_and_space_poke_the_box() {
+		let mut track = track();
+		let mut tree = tree(&track);
+
+		let (statuses, messages) = send(
+			&mut track,
+			&mut tree,
+			vec![
+				click(),
+				key(Key::Named(Named::Enter)),
+				key(Key::Named(Named::Space)),
+				key(Key::Character("a".into())),
+			],
+		);
+
+		assert_eq!(messages, vec![Wound::None; 3]);
+		assert_eq!(statuses[3], event::Status::Ignored);
+	}
+
+	#[test]
+	fn escape_gives_up_focus() {
+		let mut track = track();
+		let mut tree = tree(&track);
+
+		let (statuses, messages) = send(
+			&mut track,
+			&mut tree,
+			vec![
+				click(),
+				key(Key::Named(Named::Escape)),
+				key(Key::Named(Named::Enter)),
+			],
+		);
+
+		assert_eq!(statuses[2], event::Status::Ignored);
+		assert_eq!(messages, vec![Wound::None]);
+	}
 }
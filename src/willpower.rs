@@ -0,0 +1,191 @@
+use cofd::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	dice::Pool, extras::CharacterExtras, fl, history::Event, i18n, i18n::Translate, modifiers,
+};
+
+const DICE_BONUS: i32 = 3;
+const RESISTANCE_BONUS: i32 = 2;
+
+/// What a point of Willpower is spent on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Spend {
+	/// +3 dice to a roll.
+	Dice,
+	/// +2 to a resistance trait against one action.
+	Resistance,
+}
+
+impl Translate for Spend {
+	fn translated(&self) -> String {
+		i18n::LANGUAGE_LOADER.get(match self {
+			Self::Dice => "spend-dice",
+			Self::Resistance => "spend-resistance",
+		})
+	}
+}
+
+/// How Willpower was regained.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Regain {
+	Vice(String),
+	Virtue(String),
+	Rest,
+}
+
+/// The character's own Virtue, falling back to what their splat calls it.
+pub fn virtue(character: &Character) -> String {
+	anchor(&character.info.virtue_anchor, || {
+		character.splat.virtue_anchor().translated()
+	})
+}
+
+/// The character's own Vice, falling back to what their splat calls it.
+pub fn vice(character: &Character) -> String {
+	anchor(&character.info.vice_anchor, || {
+		character.splat.vice_anchor().translated()
+	})
+}
+
+fn anchor(name: &str, fallback: impl FnOnce() -> String) -> String {
+	if name.is_empty() {
+		fallback()
+	} else {
+		name.to_string()
+	}
+}
+
+/// Only one point of Willpower goes into a single roll.
+pub fn can_spend(character: &Character, extras: &CharacterExtras) -> bool {
+	character.willpower > 0 && extras.willpower_spend.is_none()
+}
+
+/// Spends a point of Willpower, which then counts towards the next roll it's for.
+pub fn spend(character: &mut Character, extras: &mut CharacterExtras, spend: Spend) -> bool {
+	if !can_spend(character, extras) {
+		return false;
+	}
+
+	character.willpower -= 1;
+	extras.willpower_spend = Some(spend);
+	extras.history.push(Event::WillpowerSpent(spend));
+	true
+}
+
+/// Adds the dice from Willpower spent on the roll.
+pub fn dice(pool: Pool, extras: &CharacterExtras) -> Pool {
+	if extras.willpower_spend == Some(Spend::Dice) {
+		pool.bonus(fl!("willpower"), DICE_BONUS)
+	} else {
+		pool
+	}
+}
+
+/// Adds Willpower spent on resisting to a Resistance pool.
+pub fn resistance(pool: Pool, extras: &CharacterExtras) -> Pool {
+	if extras.willpower_spend == Some(Spend::Resistance) {
+		pool.bonus(fl!("willpower"), RESISTANCE_BONUS)
+	} else {
+		pool
+	}
+}
+
+/// Clears Willpower spent on a roll once it's been rolled.
+pub fn used(extras: &mut CharacterExtras, spend: Spend) {
+	if extras.willpower_spend == Some(spend) {
+		extras.willpower_spend = None;
+	}
+}
+
+/// Regaining through the Vice works once per scene.
+pub fn can_indulge(character: &Character, extras: &CharacterExtras) -> bool {
+	!extras.session.vice && character.willpower < modifiers::max_willpower(character, extras)
+}
+
+/// Regaining through the Virtue works once per chapter.
+pub fn can_uphold(character: &Character, extras: &CharacterExtras) -> bool {
+	!extras.session.virtue && character.willpower < modifiers::max_willpower(character, extras)
+}
+
+pub fn can_rest(character: &Character, extras: &CharacterExtras) -> bool {
+	character.willpower < modifiers::max_willpower(character, extras)
+}
+
+/// Restores up to `amount` Willpower, returning how much was actually regained.
+pub fn recover(character: &mut Character, extras: &CharacterExtras, amount: u16) -> u16 {
+	let before = character.willpower;
+	character.willpower = before
+		.saturating_add(amount)
+		.min(modifiers::max_willpower(character, extras));
+
+	character.willpower.saturating_sub(before)
+}
+
+fn regain(character: &mut Character, extras: &mut CharacterExtras, regain: Regain, amount: u16) {
+	let amount = recover(character, extras, amount);
+	extras
+		.history
+		.push(Event::WillpowerRegained { regain, amount });
+}
+
+/// Acting on the Vice restores one Willpower.
+pub fn indulge(character: &mut Character, extras: &mut CharacterExtras) {
+	if can_indulge(character, extras) {
+		extras.session.vice = true;
+		regain(character, extras, Regain::Vice(vice(character)), 1);
+	}
+}
+
+/// Acting on the Virtue at a cost restores all Willpower.
+pub fn uphold(character: &mut Character, extras: &mut CharacterExtras) {
+	if can_uphold(character, extras) {
+		extras.session.virtue = true;
+		let max = modifiers::max_willpower(character, extras);
+		regain(character, extras, Regain::Virtue(virtue(character)), max);
+	}
+}
+
+/// A full night's rest restores one Willpower.
+pub fn rest(character: &mut Character, extras: &mut CharacterExtras) {
+	if can_rest(character, extras) {
+		regain(character, extras, Regain::Rest, 1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn spend_counts_towards_one_roll() {
+		let mut character = Character::default();
+		character.willpower = 2;
+		let mut extras = CharacterExtras::default();
+
+		assert!(spend(&mut character, &mut extras, Spend::Dice));
+		assert_eq!(character.willpower, 1);
+		assert!(!spend(&mut character, &mut extras, Spend::Dice));
+		assert_eq!(character.willpower, 1);
+
+		assert_eq!(dice(Pool::new(), &extras).total(), 3);
+		assert_eq!(resistance(Pool::new(), &extras).total(), 0);
+
+		used(&mut extras, Spend::Resistance);
+		assert_eq!(extras.willpower_spend, Some(Spend::Dice));
+		used(&mut extras, Spend::Dice);
+		assert_eq!(dice(Pool::new(), &extras).total(), 0);
+	}
+
+	#[test]
+	fn resistance_spend_adds_two() {
+		let mut character = Character::default();
+		character.willpower = 1;
+		let mut extras = CharacterExtras::default();
+
+		assert!(spend(&mut character, &mut extras, Spend::Resistance));
+		assert_eq!(resistance(Pool::new(), &extras).total(), 2);
+		assert_eq!(dice(Pool::new(), &extras).total(), 0);
+		assert!(!can_spend(&character, &extras));
+	}
+}